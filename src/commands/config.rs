@@ -1,16 +1,25 @@
+use std::path::{Path, MAIN_SEPARATOR};
+
 use derive_more::Display;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{space_prefixed, then_or_empty};
+use crate::error::IncludePathError;
 
 /// The following commands may only be used in the configuration file.
 #[derive(Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum ConfigCommand {
     //  sway-output(5)
     // TODO sway-bar(5)
     // TODO quote string containing commands
     /// For details on bar subcommands, see sway-bar(5).
     #[display(
-        fmt = "bar {} {}",
-        "_0.as_deref().unwrap_or_default()",
-        "_1.join(\" \")"
+        fmt = "bar{}{}",
+        "then_or_empty(_0, |id| format!(\" {id}\"))",
+        "space_prefixed(_1)"
     )]
     Bar(Option<String>, Vec<String>),
     /// Sets the default container layout for tiled containers.
@@ -53,18 +62,95 @@ pub enum ConfigCommand {
     Xwayland(Xwayland),
 }
 
-#[derive(Display)]
+impl ConfigCommand {
+    /// Includes the file at `path`, see [`Self::Include`]
+    ///
+    /// The path is converted to a string using forward slashes as separators,
+    /// whitespace is escaped with `\`. The same file can only be included
+    /// once, subsequent includes are ignored by sway.
+    ///
+    /// ```
+    /// # use sway_command::commands::*;
+    /// assert_eq!(
+    ///     ConfigCommand::include("/etc/sway/config.d/50-systemd-user.conf")?.to_string(),
+    ///     "include /etc/sway/config.d/50-systemd-user.conf"
+    /// );
+    /// assert_eq!(
+    ///     ConfigCommand::include("/home/me/my config")?.to_string(),
+    ///     r"include /home/me/my\ config"
+    /// );
+    /// assert!(ConfigCommand::include("").is_err());
+    /// # Ok::<(), sway_command::error::IncludePathError>(())
+    /// ```
+    pub fn include(path: impl AsRef<Path>) -> Result<Self, IncludePathError> {
+        let path = path.as_ref().to_string_lossy();
+        if path.is_empty() {
+            return Err(IncludePathError);
+        }
+        Ok(Self::Include(escape_whitespace(
+            &path.replace(MAIN_SEPARATOR, "/"),
+        )))
+    }
+
+    /// Includes all files matching the glob `pattern`, see [`Self::Include`]
+    ///
+    /// The pattern is expanded by sway, e.g. `~/.config/sway/config.d/*`, only
+    /// whitespace is escaped with `\`. Files that were already included are
+    /// ignored.
+    ///
+    /// ```
+    /// # use sway_command::commands::*;
+    /// assert_eq!(
+    ///     ConfigCommand::include_glob("~/.config/sway/config.d/*")?.to_string(),
+    ///     "include ~/.config/sway/config.d/*"
+    /// );
+    /// assert!(ConfigCommand::include_glob("").is_err());
+    /// # Ok::<(), sway_command::error::IncludePathError>(())
+    /// ```
+    pub fn include_glob(pattern: &str) -> Result<Self, IncludePathError> {
+        if pattern.is_empty() {
+            return Err(IncludePathError);
+        }
+        Ok(Self::Include(escape_whitespace(pattern)))
+    }
+}
+
+/// Escapes whitespace with `\`, as sway expands include paths using
+/// wordexp(3)
+fn escape_whitespace(path: &str) -> String {
+    let mut escaped = String::with_capacity(path.len());
+    for c in path.chars() {
+        if c.is_whitespace() {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[derive(Display, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum DefaultOrientation {
     #[display(fmt = "horizontal")]
     Horizontal,
     #[display(fmt = "vertical")]
     Vertical,
+    /// Horizontal, unless the output is rotated or the display is taller than
+    /// it is wide
+    ///
+    /// This is the default according to sway(5).
+    #[default]
     #[display(fmt = "auto")]
     Auto,
 }
 
-#[derive(Display)]
+#[derive(Display, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum WorkspaceLayout {
+    /// This is the default according to sway(5).
+    #[default]
     #[display(fmt = "default")]
     Default,
     #[display(fmt = "stacking")]
@@ -73,8 +159,15 @@ pub enum WorkspaceLayout {
     Tabbed,
 }
 
-#[derive(Display)]
+#[derive(Display, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Xwayland {
+    /// Lazily load Xwayland, it will not be launched until the first client
+    /// attempts to connect
+    ///
+    /// This is the default according to sway(5).
+    #[default]
     #[display(fmt = "enable")]
     Enable,
     #[display(fmt = "disable")]
@@ -82,3 +175,58 @@ pub enum Xwayland {
     #[display(fmt = "force")]
     Force,
 }
+
+/// Position of a bar, see sway-bar(5)
+#[derive(Display, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum BarPosition {
+    /// Top edge of the output
+    #[display(fmt = "top")]
+    Top,
+    /// Bottom edge of the output
+    ///
+    /// This is the default according to sway-bar(5).
+    #[default]
+    #[display(fmt = "bottom")]
+    Bottom,
+}
+
+/// Visibility mode of a bar, see sway-bar(5)
+#[derive(Display, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum BarMode {
+    /// The bar is permanently visible at one edge of the screen
+    ///
+    /// This is the default according to sway-bar(5).
+    #[default]
+    #[display(fmt = "dock")]
+    Dock,
+    /// The bar is hidden unless the modifier key is pressed, though this
+    /// behaviour depends on the hidden state
+    #[display(fmt = "hide")]
+    Hide,
+    /// The bar is permanently hidden
+    #[display(fmt = "invisible")]
+    Invisible,
+    /// The bar is permanently visible on top of other windows
+    #[display(fmt = "overlay")]
+    Overlay,
+}
+
+/// Hidden state of a bar in [`BarMode::Hide`], see sway-bar(5)
+#[derive(Display, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum BarHiddenState {
+    /// The bar is hidden unless the modifier key is pressed
+    ///
+    /// This is the default according to sway-bar(5).
+    #[default]
+    #[display(fmt = "hide")]
+    Hide,
+    /// The bar is permanently visible, as if it was in [`BarMode::Dock`]
+    #[display(fmt = "show")]
+    Show,
+}
@@ -1,23 +1,56 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt};
 
 use derive_more::Display;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use super::{separated, to_string_or_empty};
 
 #[derive(Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Font {
     #[display(fmt = "pango:{_0}")]
     Pango(FontDescription),
     Normal(FontDescription),
 }
 
-#[derive(Display)]
-#[display(
-    fmt = "{} {style_options} {} {}",
-    "separated(families, ',')",
-    "to_string_or_empty(size)",
-    "separated(variations.iter().map(|(axis,value)| format!(\"`{axis}`={value}\")), ',')"
-)]
+impl Font {
+    /// Font rendered by pango, see [`Self::Pango`]
+    pub fn pango(description: FontDescription) -> Font {
+        Font::Pango(description)
+    }
+
+    /// Font without the `pango:` prefix, see [`Self::Normal`]
+    pub fn normal(description: FontDescription) -> Font {
+        Font::Normal(description)
+    }
+
+    /// Whether this is [`Self::Pango`]
+    pub fn is_pango(&self) -> bool {
+        matches!(self, Font::Pango(_))
+    }
+
+    /// The description of the font, independent of the variant
+    pub fn description(&self) -> &FontDescription {
+        match self {
+            Font::Pango(description) | Font::Normal(description) => description,
+        }
+    }
+}
+
+/// Creates a [`Font::Normal`], use [`Font::pango`] to explicitly prefix the
+/// font with `pango:`
+impl From<FontDescription> for Font {
+    fn from(description: FontDescription) -> Self {
+        Font::Normal(description)
+    }
+}
+
+/// Displays the families, style options, size and variations separated by a
+/// space, leaving out the empty ones
+#[derive(Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FontDescription {
     families: Vec<String>,
     style_options: FontStyleOptions,
@@ -25,15 +58,99 @@ pub struct FontDescription {
     variations: HashMap<String, String>,
 }
 
-#[derive(Display, Default)]
-#[display(
-    fmt = "{} {} {} {} {}",
-    "to_string_or_empty(style)",
-    "to_string_or_empty(variant)",
-    "to_string_or_empty(weight)",
-    "to_string_or_empty(stretch)",
-    "to_string_or_empty(gravity)"
-)]
+impl FontDescription {
+    /// Creates a description of the font `family`, more fallback families can
+    /// be added with [`Self::family`]
+    ///
+    /// ```
+    /// # use sway_command::commands::*;
+    /// let font = Font::pango(
+    ///     FontDescription::new("monospace")
+    ///         .family("Noto Color Emoji")
+    ///         .weight(FontWeight::Bold)
+    ///         .size(FontSize::Pt(10.0)),
+    /// );
+    /// assert_eq!(font.to_string(), "pango:monospace,Noto Color Emoji Bold 10");
+    /// ```
+    pub fn new(family: impl Into<String>) -> Self {
+        Self {
+            families: vec![family.into()],
+            ..Default::default()
+        }
+    }
+
+    /// Adds a fallback family
+    pub fn family(mut self, family: impl Into<String>) -> Self {
+        self.families.push(family.into());
+        self
+    }
+
+    /// Sets the [`FontStyle`]
+    pub fn style(mut self, style: FontStyle) -> Self {
+        self.style_options.style = Some(style);
+        self
+    }
+
+    /// Sets the [`FontVariant`]
+    pub fn variant(mut self, variant: FontVariant) -> Self {
+        self.style_options.variant = Some(variant);
+        self
+    }
+
+    /// Sets the [`FontWeight`]
+    pub fn weight(mut self, weight: FontWeight) -> Self {
+        self.style_options.weight = Some(weight);
+        self
+    }
+
+    /// Sets the [`FontStretch`]
+    pub fn stretch(mut self, stretch: FontStretch) -> Self {
+        self.style_options.stretch = Some(stretch);
+        self
+    }
+
+    /// Sets the [`FontGravity`]
+    pub fn gravity(mut self, gravity: FontGravity) -> Self {
+        self.style_options.gravity = Some(gravity);
+        self
+    }
+
+    /// Sets the [`FontSize`]
+    pub fn size(mut self, size: FontSize) -> Self {
+        self.size = Some(size);
+        self
+    }
+}
+
+impl fmt::Display for FontDescription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts = [
+            separated(&self.families, ','),
+            self.style_options.to_string(),
+            to_string_or_empty(&self.size),
+            separated(
+                self.variations
+                    .iter()
+                    .map(|(axis, value)| format!("`{axis}`={value}")),
+                ',',
+            ),
+        ];
+        write!(
+            f,
+            "{}",
+            separated(parts.into_iter().filter(|part| !part.is_empty()), ' ')
+        )
+    }
+}
+
+/// Style modifiers of a [`FontDescription`]
+///
+/// [`FontStyleOptions::default`] sets no modifiers, which makes pango use the
+/// default style of the font.
+///
+/// Displays only the set modifiers separated by a space.
+#[derive(Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FontStyleOptions {
     style: Option<FontStyle>,
     variant: Option<FontVariant>,
@@ -42,19 +159,48 @@ pub struct FontStyleOptions {
     gravity: Option<FontGravity>,
 }
 
+impl fmt::Display for FontStyleOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let options = [
+            to_string_or_empty(&self.style),
+            to_string_or_empty(&self.variant),
+            to_string_or_empty(&self.weight),
+            to_string_or_empty(&self.stretch),
+            to_string_or_empty(&self.gravity),
+        ];
+        write!(
+            f,
+            "{}",
+            separated(options.into_iter().filter(|option| !option.is_empty()), ' ')
+        )
+    }
+}
+
+/// Style of a font, see [`FontStyleOptions`]
 #[derive(Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum FontStyle {
+    /// Upright style, this is what pango uses when no style is given
+    ///
+    /// Pango accepts the explicit `Normal`, so `pango:monospace Normal 10` is
+    /// equivalent to `pango:monospace 10`.
     #[display(fmt = "Normal")]
     Normal,
+    /// Alternative spelling of [`Self::Normal`] accepted by pango
     #[display(fmt = "Roman")]
     Roman,
+    /// Slanted style
     #[display(fmt = "Oblique")]
     Oblique,
+    /// Italic style
     #[display(fmt = "Italic")]
     Italic,
 }
 
 #[derive(Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum FontVariant {
     #[display(fmt = "Small-Caps")]
     SmallCaps,
@@ -71,6 +217,8 @@ pub enum FontVariant {
 }
 
 #[derive(Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum FontWeight {
     #[display(fmt = "Thin")]
     Thin,
@@ -111,6 +259,8 @@ pub enum FontWeight {
 }
 
 #[derive(Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum FontStretch {
     #[display(fmt = "Ultra-Condensed")]
     UltraCondensed,
@@ -131,6 +281,8 @@ pub enum FontStretch {
 }
 
 #[derive(Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum FontGravity {
     #[display(fmt = "Not-Rotated")]
     NotRotated,
@@ -150,9 +302,128 @@ pub enum FontGravity {
     West,
 }
 
+/// Size of a font
+///
+/// Whole numbers are displayed without a decimal point, matching the sizes
+/// used in hand-written configs:
+/// ```
+/// # use sway_command::commands::FontSize;
+/// assert_eq!(FontSize::Pt(10.0).to_string(), "10");
+/// assert_eq!(FontSize::Pt(10.5).to_string(), "10.5");
+/// ```
 #[derive(Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum FontSize {
+    /// Size in points
+    #[display(fmt = "{_0}")]
     Pt(f32),
+    /// Size in pixels
+    ///
+    /// Pango accepts fractional pixel sizes, use [`FontSize::px_int`] to
+    /// ensure a whole number.
     #[display(fmt = "{_0} px")]
     Px(f32),
 }
+
+impl FontSize {
+    /// Size in whole pixels, see [`Self::Px`]
+    ///
+    /// ```
+    /// # use sway_command::commands::FontSize;
+    /// assert_eq!(FontSize::px_int(12).to_string(), "12 px");
+    /// ```
+    pub fn px_int(px: u32) -> Self {
+        Self::Px(px as f32)
+    }
+}
+
+/// Compares the floating point values bitwise, so `NaN` is equal to itself and
+/// `0.0` is not equal to `-0.0`
+impl PartialEq for FontSize {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (FontSize::Pt(a), FontSize::Pt(b)) | (FontSize::Px(a), FontSize::Px(b)) => {
+                a.to_bits() == b.to_bits()
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Eq for FontSize {}
+
+#[test]
+fn font_from_description() {
+    let description = || FontDescription {
+        families: vec!["monospace".into()],
+        style_options: Default::default(),
+        size: None,
+        variations: Default::default(),
+    };
+    let font = Font::from(description());
+    assert!(!font.is_pango());
+    assert_eq!(font.description().families, ["monospace"]);
+    assert!(Font::pango(description()).is_pango());
+    assert_eq!(Font::pango(description()).to_string(), "pango:monospace");
+    assert_eq!(Font::normal(description()).to_string(), "monospace");
+}
+
+#[test]
+fn font_description_display() {
+    let description = FontDescription {
+        families: vec!["monospace".into(), "Noto Color Emoji".into()],
+        size: Some(FontSize::Pt(10.0)),
+        ..Default::default()
+    };
+    assert_eq!(description.to_string(), "monospace,Noto Color Emoji 10");
+    let description = FontDescription {
+        families: vec!["monospace".into()],
+        style_options: FontStyleOptions {
+            weight: Some(FontWeight::Bold),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    assert_eq!(Font::pango(description).to_string(), "pango:monospace Bold");
+}
+
+#[test]
+fn font_size_eq() {
+    assert!(FontSize::Pt(10.0) == FontSize::Pt(10.0));
+    assert!(FontSize::Pt(f32::NAN) == FontSize::Pt(f32::NAN));
+    assert!(FontSize::Pt(0.0) != FontSize::Pt(-0.0));
+    assert!(FontSize::Pt(10.0) != FontSize::Px(10.0));
+}
+
+#[test]
+fn font_size_display() {
+    assert_eq!(FontSize::Pt(10.0).to_string(), "10");
+    assert_eq!(FontSize::Pt(10.5).to_string(), "10.5");
+    assert_eq!(FontSize::Pt(0.0).to_string(), "0");
+    assert_eq!(FontSize::Pt(100000.0).to_string(), "100000");
+    assert_eq!(FontSize::Px(12.5).to_string(), "12.5 px");
+    assert!(FontSize::px_int(12) == FontSize::Px(12.0));
+}
+
+#[test]
+fn default_font_style_options() {
+    assert_eq!(FontStyleOptions::default().to_string(), "");
+}
+
+#[test]
+fn font_style() {
+    let options = FontStyleOptions {
+        style: Some(FontStyle::Normal),
+        ..Default::default()
+    };
+    assert_eq!(options.to_string(), "Normal");
+    let options = FontStyleOptions {
+        style: Some(FontStyle::Italic),
+        gravity: Some(FontGravity::South),
+        ..Default::default()
+    };
+    assert_eq!(options.to_string(), "Italic South");
+    assert_eq!(FontStyle::Roman.to_string(), "Roman");
+    assert_eq!(FontStyle::Italic.to_string(), "Italic");
+}
@@ -0,0 +1,201 @@
+use derive_more::Display;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::separated;
+use crate::error::XkbOptionError;
+
+/// Device an `input` or `seat` command applies to
+///
+/// Converting from a string maps `*` to [`InputDeviceIdentifier::All`]:
+/// ```
+/// # use sway_command::commands::*;
+/// assert!(matches!(InputDeviceIdentifier::from("*"), InputDeviceIdentifier::All));
+/// assert_eq!(InputDeviceIdentifier::named("type:touchpad").to_string(), "type:touchpad");
+/// ```
+#[derive(Display, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum InputDeviceIdentifier {
+    /// All devices
+    #[display(fmt = "*")]
+    All,
+    /// A device identifier as listed by `swaymsg -t get_inputs`, a
+    /// `type:<type>` or a seat name
+    #[display(fmt = "{_0}")]
+    Named(String),
+}
+
+impl InputDeviceIdentifier {
+    /// Matches all devices, see [`Self::All`]
+    pub fn all() -> Self {
+        Self::All
+    }
+
+    /// Matches the device with `name`, see [`Self::Named`]
+    pub fn named(name: &str) -> Self {
+        Self::Named(name.to_owned())
+    }
+}
+
+impl From<String> for InputDeviceIdentifier {
+    fn from(name: String) -> Self {
+        if name == "*" {
+            Self::All
+        } else {
+            Self::Named(name)
+        }
+    }
+}
+
+impl From<&str> for InputDeviceIdentifier {
+    fn from(name: &str) -> Self {
+        name.to_owned().into()
+    }
+}
+
+/// A single XKB option in the form `group:option`, e.g. `caps:escape`
+///
+/// See xkeyboard-config(7) for the available options.
+#[derive(Display, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String", into = "String"))]
+pub struct XkbOption(String);
+
+impl XkbOption {
+    /// Validates that `option` is of the form `group:option`
+    ///
+    /// ```
+    /// # use sway_command::commands::XkbOption;
+    /// assert!(XkbOption::new("caps:escape").is_ok());
+    /// assert!(XkbOption::new("caps_escape").is_err());
+    /// ```
+    pub fn new(option: impl Into<String>) -> Result<Self, XkbOptionError> {
+        let option = option.into();
+        match option.split_once(':') {
+            Some((group, name))
+                if !group.is_empty()
+                    && !name.is_empty()
+                    && !option.contains(|c: char| c == ',' || c.is_whitespace()) =>
+            {
+                Ok(Self(option))
+            }
+            _ => Err(XkbOptionError { option }),
+        }
+    }
+}
+
+impl TryFrom<String> for XkbOption {
+    type Error = XkbOptionError;
+
+    fn try_from(option: String) -> Result<Self, Self::Error> {
+        Self::new(option)
+    }
+}
+
+impl TryFrom<&str> for XkbOption {
+    type Error = XkbOptionError;
+
+    fn try_from(option: &str) -> Result<Self, Self::Error> {
+        Self::new(option)
+    }
+}
+
+impl From<XkbOption> for String {
+    fn from(XkbOption(option): XkbOption) -> Self {
+        option
+    }
+}
+
+/// List of [`XkbOption`]s for the `xkb_options` input subcommand
+///
+/// Options are added with [`Self::push`] or [`Self::builder`], and turned into
+/// the arguments of
+/// [`CriterialessCommand::Input`](super::CriterialessCommand::Input) with
+/// [`Self::into_input_args`]:
+/// ```
+/// # use sway_command::commands::*;
+/// let options = XkbOptions::builder()
+///     .option("caps:escape")?
+///     .option("altwin:swap_alt_win")?
+///     .build();
+/// let cmd = CriterialessCommand::Input("type:keyboard".into(), options.into_input_args());
+/// assert_eq!(
+///     cmd.to_string(),
+///     "input type:keyboard xkb_options caps:escape,altwin:swap_alt_win"
+/// );
+/// # Ok::<(), sway_command::error::XkbOptionError>(())
+/// ```
+#[derive(Display, Default, Clone, PartialEq, Eq, Hash)]
+#[display(fmt = "{}", "separated(_0, ',')")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct XkbOptions(Vec<XkbOption>);
+
+impl XkbOptions {
+    /// Creates an [`XkbOptionsBuilder`] without any options
+    pub fn builder() -> XkbOptionsBuilder {
+        XkbOptionsBuilder::default()
+    }
+
+    /// Adds an option after validating it, see [`XkbOption::new`]
+    ///
+    /// ```
+    /// # use sway_command::commands::*;
+    /// let mut options = XkbOptions::default();
+    /// options.push("caps:escape")?.push("altwin:swap_alt_win")?;
+    /// assert_eq!(options.to_string(), "caps:escape,altwin:swap_alt_win");
+    /// assert!(options.push("caps_escape").is_err());
+    /// # Ok::<(), sway_command::error::XkbOptionError>(())
+    /// ```
+    pub fn push(&mut self, option: &str) -> Result<&mut Self, XkbOptionError> {
+        self.0.push(XkbOption::new(option)?);
+        Ok(self)
+    }
+
+    /// Get the options
+    pub fn get_options(&self) -> &[XkbOption] {
+        &self.0
+    }
+
+    /// The `xkb_options <options>` arguments of
+    /// [`CriterialessCommand::Input`](super::CriterialessCommand::Input)
+    ///
+    /// ```
+    /// # use sway_command::commands::*;
+    /// let options = XkbOptions::builder().option("caps:escape")?.build();
+    /// assert_eq!(options.into_input_args(), ["xkb_options", "caps:escape"]);
+    /// # Ok::<(), sway_command::error::XkbOptionError>(())
+    /// ```
+    pub fn into_input_args(self) -> Vec<String> {
+        vec!["xkb_options".to_owned(), self.to_string()]
+    }
+}
+
+impl From<Vec<XkbOption>> for XkbOptions {
+    fn from(options: Vec<XkbOption>) -> Self {
+        Self(options)
+    }
+}
+
+/// Builder for [`XkbOptions`], created by [`XkbOptions::builder`]
+#[derive(Default)]
+pub struct XkbOptionsBuilder(XkbOptions);
+
+impl XkbOptionsBuilder {
+    /// Adds an option after validating it, see [`XkbOption::new`]
+    pub fn option(mut self, option: &str) -> Result<Self, XkbOptionError> {
+        self.0.push(option)?;
+        Ok(self)
+    }
+
+    /// Creates the [`XkbOptions`]
+    pub fn build(self) -> XkbOptions {
+        self.0
+    }
+}
+
+impl From<XkbOptionsBuilder> for XkbOptions {
+    fn from(builder: XkbOptionsBuilder) -> Self {
+        builder.build()
+    }
+}
@@ -1,4 +1,10 @@
+use std::{fmt, ops::Not, str::FromStr};
+
 use derive_more::Display;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::{GapsDirectionParseError, WorkspaceParseError};
 
 mod config;
 pub use config::*;
@@ -12,15 +18,23 @@ pub use standalone::*;
 mod font;
 pub use font::*;
 
+mod input;
+pub use input::*;
+
+mod output;
+pub use output::*;
+
 #[derive(Display, Clone)]
 /// Workspace Selector
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Workspace {
     /// Workspace name
-    #[display(fmt = "_0")]
+    #[display(fmt = "{_0}")]
     Name(WorkspaceName),
     /// Also matches a workspace with the same number, even if it has a
     /// different name
-    #[display(fmt = "number _0")]
+    #[display(fmt = "number {_0}")]
     Number(WorkspaceName),
     /// Moves the focused container to the previous workspace on this output, or
     /// if no workspaces remain, the previous output
@@ -49,6 +63,8 @@ pub enum Workspace {
 
 #[derive(Display, Clone)]
 /// Name of a workspace
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum WorkspaceName {
     /// Name without additional index
     Simple(String),
@@ -57,8 +73,92 @@ pub enum WorkspaceName {
     WithNumber(u32, String),
 }
 
+/// Parses the workspace selector as used by sway
+///
+/// ```
+/// # use sway_command::commands::*;
+/// assert!(matches!(
+///     "1".parse(),
+///     Ok(Workspace::Name(WorkspaceName::Simple(name))) if name == "1"
+/// ));
+/// assert!(matches!(
+///     Workspace::try_from("number 5"),
+///     Ok(Workspace::Number(WorkspaceName::Simple(name))) if name == "5"
+/// ));
+/// assert!(matches!("back_and_forth".parse(), Ok(Workspace::BackAndForth)));
+/// assert!("".parse::<Workspace>().is_err());
+/// ```
+impl FromStr for Workspace {
+    type Err = WorkspaceParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim() {
+            "prev" => Workspace::Prev,
+            "next" => Workspace::Next,
+            "current" => Workspace::Current,
+            "prev_on_output" => Workspace::PrevOnOutput,
+            "next_on_output" => Workspace::NextOnOutput,
+            "back_and_forth" => Workspace::BackAndForth,
+            s => match s.strip_prefix("number ") {
+                Some(name) => Workspace::Number(name.parse()?),
+                None => Workspace::Name(s.parse()?),
+            },
+        })
+    }
+}
+
+impl TryFrom<&str> for Workspace {
+    type Error = WorkspaceParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// Parses `<number>:<name>` as [`WorkspaceName::WithNumber`] and everything
+/// else as [`WorkspaceName::Simple`]
+///
+/// ```
+/// # use sway_command::commands::*;
+/// assert!(matches!(
+///     "3:mail".parse(),
+///     Ok(WorkspaceName::WithNumber(3, name)) if name == "mail"
+/// ));
+/// assert!(matches!(
+///     WorkspaceName::try_from("mail"),
+///     Ok(WorkspaceName::Simple(name)) if name == "mail"
+/// ));
+/// ```
+impl FromStr for WorkspaceName {
+    type Err = WorkspaceParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(WorkspaceParseError);
+        }
+        Ok(match s.split_once(':') {
+            Some((number, name)) if !name.is_empty() => match number.parse() {
+                Ok(number) => WorkspaceName::WithNumber(number, name.to_owned()),
+                Err(_) => WorkspaceName::Simple(s.to_owned()),
+            },
+            _ => WorkspaceName::Simple(s.to_owned()),
+        })
+    }
+}
+
+impl TryFrom<&str> for WorkspaceName {
+    type Error = WorkspaceParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
 #[derive(Display, Clone)]
 /// Output Selector
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Output {
     /// Next output in the specified direction
     #[display(fmt = "up")]
@@ -82,6 +182,8 @@ pub enum Output {
 #[derive(Display, Clone)]
 /// Direction of Gaps
 #[allow(missing_docs)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum GapsDirection {
     #[display(fmt = "inner")]
     Inner,
@@ -101,8 +203,35 @@ pub enum GapsDirection {
     Left,
 }
 
-#[derive(Display, Clone)]
+/// Parses the direction case-insensitively
+///
+/// ```
+/// # use sway_command::commands::GapsDirection;
+/// assert!(matches!("Inner".parse(), Ok(GapsDirection::Inner)));
+/// assert!("inside".parse::<GapsDirection>().is_err());
+/// ```
+impl FromStr for GapsDirection {
+    type Err = GapsDirectionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_ascii_lowercase().as_str() {
+            "inner" => GapsDirection::Inner,
+            "outer" => GapsDirection::Outer,
+            "horizontal" => GapsDirection::Horizontal,
+            "vertical" => GapsDirection::Vertical,
+            "top" => GapsDirection::Top,
+            "right" => GapsDirection::Right,
+            "bottom" => GapsDirection::Bottom,
+            "left" => GapsDirection::Left,
+            _ => return Err(GapsDirectionParseError { value: s.into() }),
+        })
+    }
+}
+
+#[derive(Display, Clone, Copy, PartialEq, Eq)]
 #[allow(missing_docs)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum YesNo {
     #[display(fmt = "yes")]
     Yes,
@@ -110,8 +239,10 @@ pub enum YesNo {
     No,
 }
 
-#[derive(Display, Clone)]
+#[derive(Display, Clone, Copy, PartialEq, Eq)]
 #[allow(missing_docs)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum EnDisable {
     #[display(fmt = "enable")]
     Enable,
@@ -119,8 +250,10 @@ pub enum EnDisable {
     Disable,
 }
 
-#[derive(Display, Clone)]
+#[derive(Display, Clone, Copy, PartialEq, Eq)]
 #[allow(missing_docs)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum EnDisTog {
     #[display(fmt = "enable")]
     Enable,
@@ -130,6 +263,101 @@ pub enum EnDisTog {
     Toggle,
 }
 
+const EN_DISABLE: [EnDisable; 2] = [EnDisable::Enable, EnDisable::Disable];
+const EN_DIS_TOG: [EnDisTog; 3] = [EnDisTog::Enable, EnDisTog::Disable, EnDisTog::Toggle];
+const YES_NO: [YesNo; 2] = [YesNo::Yes, YesNo::No];
+
+impl EnDisable {
+    /// Returns `true` for [`EnDisable::Enable`]
+    pub fn is_enabled(&self) -> bool {
+        matches!(self, EnDisable::Enable)
+    }
+
+    /// Returns `true` for [`EnDisable::Disable`]
+    pub fn is_disabled(&self) -> bool {
+        matches!(self, EnDisable::Disable)
+    }
+}
+
+impl EnDisTog {
+    /// Returns `true` for [`EnDisTog::Enable`]
+    pub fn is_enabled(&self) -> bool {
+        matches!(self, EnDisTog::Enable)
+    }
+
+    /// Returns `true` for [`EnDisTog::Disable`]
+    pub fn is_disabled(&self) -> bool {
+        matches!(self, EnDisTog::Disable)
+    }
+
+    /// Returns `true` for [`EnDisTog::Toggle`]
+    pub fn is_toggle(&self) -> bool {
+        matches!(self, EnDisTog::Toggle)
+    }
+
+    /// Swaps [`EnDisTog::Enable`] and [`EnDisTog::Disable`], leaving
+    /// [`EnDisTog::Toggle`] untouched
+    ///
+    /// ```
+    /// # use sway_command::commands::EnDisTog;
+    /// assert!(EnDisTog::Enable.toggle().is_disabled());
+    /// assert!(EnDisTog::Disable.toggle().is_enabled());
+    /// assert!(EnDisTog::Toggle.toggle().is_toggle());
+    /// ```
+    pub fn toggle(self) -> Self {
+        match self {
+            EnDisTog::Enable => EnDisTog::Disable,
+            EnDisTog::Disable => EnDisTog::Enable,
+            EnDisTog::Toggle => EnDisTog::Toggle,
+        }
+    }
+}
+
+/// ```
+/// # use sway_command::commands::*;
+/// assert!(!YesNo::Yes == YesNo::No);
+/// ```
+impl Not for YesNo {
+    type Output = YesNo;
+
+    fn not(self) -> Self::Output {
+        match self {
+            YesNo::Yes => YesNo::No,
+            YesNo::No => YesNo::Yes,
+        }
+    }
+}
+
+/// ```
+/// # use sway_command::commands::*;
+/// assert!(!EnDisable::Enable == EnDisable::Disable);
+/// ```
+impl Not for EnDisable {
+    type Output = EnDisable;
+
+    fn not(self) -> Self::Output {
+        match self {
+            EnDisable::Enable => EnDisable::Disable,
+            EnDisable::Disable => EnDisable::Enable,
+        }
+    }
+}
+
+/// Same as [`EnDisTog::toggle`], [`EnDisTog::Toggle`] stays unchanged
+///
+/// ```
+/// # use sway_command::commands::*;
+/// assert!(!EnDisTog::Enable == EnDisTog::Disable);
+/// assert!(!EnDisTog::Toggle == EnDisTog::Toggle);
+/// ```
+impl Not for EnDisTog {
+    type Output = EnDisTog;
+
+    fn not(self) -> Self::Output {
+        self.toggle()
+    }
+}
+
 fn when(condition: bool, then: &str) -> &str {
     if condition {
         then
@@ -145,6 +373,18 @@ fn to_string_or_empty(value: &Option<impl ToString>) -> String {
     value.as_ref().map(ToString::to_string).unwrap_or_default()
 }
 
+/// Finds the variant displayed as `value`, used to parse keyword arguments
+fn from_keyword<T: fmt::Display>(value: &str, variants: impl IntoIterator<Item = T>) -> Option<T> {
+    variants
+        .into_iter()
+        .find(|variant| variant.to_string() == value)
+}
+
+/// Precedes every value with a space, so no space is left without values
+fn space_prefixed(values: impl IntoIterator<Item = impl fmt::Display>) -> String {
+    values.into_iter().map(|v| format!(" {v}")).collect()
+}
+
 fn separated(values: impl IntoIterator<Item = impl ToString>, seperator: impl ToString) -> String {
     values
         .into_iter()
@@ -152,3 +392,14 @@ fn separated(values: impl IntoIterator<Item = impl ToString>, seperator: impl To
         .collect::<Vec<String>>()
         .join(&seperator.to_string())
 }
+
+#[test]
+fn workspace_display() {
+    let name = || WorkspaceName::WithNumber(1, "web".into());
+    assert_eq!(Workspace::Name(name()).to_string(), "1:web");
+    assert_eq!(Workspace::Number(name()).to_string(), "number 1:web");
+    assert_eq!(
+        Workspace::Number(WorkspaceName::Simple("5".into())).to_string(),
+        "number 5"
+    );
+}
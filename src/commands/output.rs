@@ -0,0 +1,42 @@
+use derive_more::Display;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Subcommand of [`CriterialessCommand::Output`](super::CriterialessCommand::Output)
+///
+/// See sway-output(5) for details.
+///
+/// ```
+/// # use sway_command::commands::*;
+/// let cmd = CriterialessCommand::Output("eDP-1".into(), vec![OutputSubcommand::Disable]);
+/// assert_eq!(cmd.to_string(), "output eDP-1 disable");
+/// ```
+#[derive(Display, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum OutputSubcommand {
+    /// Enables the output
+    #[display(fmt = "enable")]
+    Enable,
+    /// Disables the output
+    #[display(fmt = "disable")]
+    Disable,
+    /// Toggles the output between enabled and disabled
+    #[display(fmt = "toggle")]
+    Toggle,
+    /// Any other subcommand, e.g. `mode 1920x1080@60Hz`
+    #[display(fmt = "{_0}")]
+    Other(String),
+}
+
+impl From<String> for OutputSubcommand {
+    fn from(subcommand: String) -> Self {
+        OutputSubcommand::Other(subcommand)
+    }
+}
+
+impl From<&str> for OutputSubcommand {
+    fn from(subcommand: &str) -> Self {
+        OutputSubcommand::Other(subcommand.to_owned())
+    }
+}
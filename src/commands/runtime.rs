@@ -1,9 +1,22 @@
+use std::{
+    ops::{Div, Mul},
+    str::FromStr,
+};
+
 use derive_more::Display;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
-use super::{to_string_or_empty, when, EnDisTog, EnDisable, GapsDirection, Output, Workspace};
+use super::{
+    from_keyword, then_or_empty, when, EnDisTog, EnDisable, GapsDirection, Output, Workspace,
+    EN_DISABLE, EN_DIS_TOG,
+};
+use crate::error::{CommandParseError, GapsModificationParseError, GapsWorkspacesParseError};
 
 #[derive(Display, Clone)]
 /// A command that can be called with a criteria
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum SubCommand {
     /// Set border style for focused window
     ///
@@ -19,19 +32,22 @@ pub enum SubCommand {
     #[display(fmt = "floating {_0}")]
     Floating(EnDisTog),
     /// Changes focused node
-    #[display(fmt = "focus {_0}")]
+    #[display(fmt = "focus{}{_0}", "when(!matches!(_0, Focus::This), \" \")")]
     Focus(Focus),
     /// Makes focused view fullscreen, non-fullscreen, or the opposite of what
     /// it is now.
     ///
     /// If global is specified, the view will be fullscreen across all outputs.
-    #[display(fmt = "focus {_0} {_1}")]
+    #[display(
+        fmt = "fullscreen {_0}{}",
+        "when(matches!(_1, FullscreenGlobal::Global), \" global\")"
+    )]
     Fullscreen(EnDisTog, FullscreenGlobal),
     /// Changes the inner or outer gaps for either all workspaces (`true`) or
     /// the current workspace (`false`). outer gaps can be altered per side
     /// with top, right, bottom, and left or per direction with horizontal
     /// and vertical.
-    #[display(fmt = "gaps {_0} {_1} {_2}, {_3}")]
+    #[display(fmt = "gaps {_0} {_1} {_2} {_3}")]
     Gaps(GapsDirection, GapsWorkspaces, GapsModification, u32),
     /// Set/unset an idle inhibitor for the view
     ///
@@ -73,7 +89,7 @@ pub enum SubCommand {
     /// A no operation command that can be used to override default behaviour.
     /// The optional comment argument is ignored, but logged for debugging
     /// purposes.
-    #[display(fmt = "nop {}", "_0.as_deref().unwrap_or_default()")]
+    #[display(fmt = "nop{}", "then_or_empty(_0, |comment| format!(\" {comment}\"))")]
     Nop(Option<String>),
     /// Reloads the sway config file and applies any changes. The config file is
     /// located at path specified by the command line arguments when started,
@@ -81,12 +97,12 @@ pub enum SubCommand {
     #[display(fmt = "reload")]
     Reload,
     /// Rename either <old_name> workspace to the <new_name>
-    #[display(fmt = "rename workspace {_0} to {_0}")]
+    #[display(fmt = "rename workspace {_0} to {_1}")]
     RenameWorkspace(String, String),
     /// Rename the focused workspace to the <new_name>
     #[display(fmt = "rename workspace to {_0}")]
     RenameFocusedWorkspace(String),
-    #[display(fmt = "resize")]
+    #[display(fmt = "resize {_0}")]
     Resize(Resize),
     /// Shows a window from the scratchpad
     ///
@@ -121,7 +137,7 @@ pub enum SubCommand {
     /// container becomes fullscreen on the same workspace as the first
     /// container. In either of those cases, the second container will gain
     /// focus.
-    #[display(fmt = "sticky {_0}")]
+    #[display(fmt = "swap container with {_0}")]
     Swap(Swap),
     /// Sets the format of window titles. The following placeholders may be
     /// used:
@@ -143,20 +159,429 @@ pub enum SubCommand {
     /// The default format is "%title".
     #[display(fmt = "title_format {_0}")]
     TitleFormat(String),
+    /// Untyped sub command, used for sub commands that cannot be parsed yet
+    #[display(fmt = "{_0}")]
+    Raw(String),
+}
+
+impl SubCommand {
+    /// Swaps with the container marked with `mark`, see [`Self::Swap`]
+    pub fn swap_with_mark(mark: impl Into<String>) -> SubCommand {
+        SubCommand::Swap(Swap::Mark(mark.into()))
+    }
+
+    /// Swaps with the container with the container id, see [`Self::Swap`]
+    pub fn swap_with_con_id(id: impl Into<String>) -> SubCommand {
+        SubCommand::Swap(Swap::ConId(id.into()))
+    }
+
+    /// Swaps with the xwayland view with the X11 window id, see [`Self::Swap`]
+    pub fn swap_with_id(id: impl Into<String>) -> SubCommand {
+        SubCommand::Swap(Swap::Id(id.into()))
+    }
+
+    /// Inhibits idle while the view is focused by any seat
+    ///
+    /// This, together with the other `inhibit_idle_*` constructors, is the
+    /// recommended way to control idle inhibition, usually combined with
+    /// criteria or `for_window`:
+    /// ```
+    /// # use sway_command::{*, commands::*, criteria::*};
+    /// let cmd = CriteriaCommand::default()
+    ///     .criteria(Criteria::AppId("mpv".to_string().into()))
+    ///     .command(SubCommand::inhibit_idle_fullscreen());
+    /// assert_eq!(cmd.to_string(), r#"[app_id="mpv"]inhibit_idle fullscreen"#);
+    /// ```
+    pub fn inhibit_idle_focus() -> SubCommand {
+        SubCommand::InhibitIdle(InhibitIdle::Focus)
+    }
+
+    /// Inhibits idle while the view is fullscreen and visible
+    pub fn inhibit_idle_fullscreen() -> SubCommand {
+        SubCommand::InhibitIdle(InhibitIdle::Fullscreen)
+    }
+
+    /// Inhibits idle until the view is closed or the inhibitor is changed
+    pub fn inhibit_idle_open() -> SubCommand {
+        SubCommand::InhibitIdle(InhibitIdle::Open)
+    }
+
+    /// Inhibits idle while the view is visible on any output
+    pub fn inhibit_idle_visible() -> SubCommand {
+        SubCommand::InhibitIdle(InhibitIdle::Visible)
+    }
+
+    /// Removes the idle inhibitor of the view
+    pub fn inhibit_idle_none() -> SubCommand {
+        SubCommand::InhibitIdle(InhibitIdle::None)
+    }
+
+    /// Sets the gaps in `direction` of the current workspace to `amount`, see
+    /// [`Self::Gaps`]
+    ///
+    /// ```
+    /// # use sway_command::commands::*;
+    /// assert!(matches!(
+    ///     SubCommand::set_gaps_current(GapsDirection::Inner, 10),
+    ///     SubCommand::Gaps(GapsDirection::Inner, GapsWorkspaces::Current, GapsModification::Set, 10)
+    /// ));
+    /// ```
+    pub fn set_gaps_current(direction: GapsDirection, amount: u32) -> SubCommand {
+        SubCommand::Gaps(
+            direction,
+            GapsWorkspaces::Current,
+            GapsModification::Set,
+            amount,
+        )
+    }
+
+    /// Sets the gaps in `direction` of all workspaces to `amount`, see
+    /// [`Self::Gaps`]
+    pub fn set_gaps_all(direction: GapsDirection, amount: u32) -> SubCommand {
+        SubCommand::Gaps(
+            direction,
+            GapsWorkspaces::All,
+            GapsModification::Set,
+            amount,
+        )
+    }
+
+    /// Sets the gaps in `direction` of the current workspace to `0`, see
+    /// [`Self::Gaps`]
+    pub fn reset_gaps_current(direction: GapsDirection) -> SubCommand {
+        Self::set_gaps_current(direction, 0)
+    }
+}
+
+/// Parses a sub command as it is displayed, e.g. `floating enable` or
+/// `resize grow width 10 px`
+///
+/// Not every command can be parsed yet, unsupported commands return an error.
+/// [`Command::parse`](crate::Command::parse) falls back to
+/// [`Command::Raw`](crate::Command::Raw) for those.
+///
+/// ```
+/// # use sway_command::commands::*;
+/// let command: SubCommand = "resize grow width 10 px".parse()?;
+/// assert_eq!(command.to_string(), "resize grow width 10 px");
+/// let command: SubCommand = "move container to workspace number 3".parse()?;
+/// assert_eq!(command.to_string(), "move container to workspace number 3");
+/// assert!("floating maybe".parse::<SubCommand>().is_err());
+/// # Ok::<(), sway_command::error::CommandParseError>(())
+/// ```
+impl FromStr for SubCommand {
+    type Err = CommandParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (name, rest) = s
+            .split_once(char::is_whitespace)
+            .map_or((s, ""), |(name, rest)| (name, rest.trim()));
+        let args: Vec<&str> = rest.split_whitespace().collect();
+        parse_sub_command(name, rest, &args).ok_or_else(|| CommandParseError {
+            command: s.to_owned(),
+        })
+    }
+}
+
+fn parse_sub_command(name: &str, rest: &str, args: &[&str]) -> Option<SubCommand> {
+    Some(match (name, args) {
+        ("border", _) => SubCommand::Border(parse_border(args)?),
+        ("exit", []) => SubCommand::Exit,
+        ("floating", [state]) => SubCommand::Floating(from_keyword(state, EN_DIS_TOG)?),
+        ("focus", _) => SubCommand::Focus(parse_focus(args)?),
+        ("fullscreen", [state]) => {
+            SubCommand::Fullscreen(from_keyword(state, EN_DIS_TOG)?, FullscreenGlobal::No)
+        }
+        ("fullscreen", [state, "global"]) => {
+            SubCommand::Fullscreen(from_keyword(state, EN_DIS_TOG)?, FullscreenGlobal::Global)
+        }
+        ("gaps", [direction, workspaces, modification, amount]) => SubCommand::Gaps(
+            direction.parse().ok()?,
+            workspaces.parse().ok()?,
+            modification.parse().ok()?,
+            amount.parse().ok()?,
+        ),
+        ("inhibit_idle", [idle]) => SubCommand::InhibitIdle(from_keyword(
+            idle,
+            [
+                InhibitIdle::Focus,
+                InhibitIdle::Fullscreen,
+                InhibitIdle::Open,
+                InhibitIdle::None,
+                InhibitIdle::Visible,
+            ],
+        )?),
+        ("layout", _) => SubCommand::Layout(parse_layout(args)?),
+        ("max_render_time", ["off"]) => SubCommand::MaxRenderTime(MaxRenderTime::Off),
+        ("max_render_time", [ms]) => {
+            SubCommand::MaxRenderTime(MaxRenderTime::Msec(ms.parse().ok()?))
+        }
+        ("move", _) => SubCommand::Move(parse_move(args)?),
+        ("nop", _) => SubCommand::Nop((!rest.is_empty()).then(|| rest.to_owned())),
+        ("reload", []) => SubCommand::Reload,
+        ("rename", ["workspace", ..]) => {
+            let rest = rest.strip_prefix("workspace")?.trim_start();
+            match rest.strip_prefix("to ") {
+                Some(new) => SubCommand::RenameFocusedWorkspace(new.trim().to_owned()),
+                None => {
+                    let (old, new) = rest.split_once(" to ")?;
+                    SubCommand::RenameWorkspace(old.trim().to_owned(), new.trim().to_owned())
+                }
+            }
+        }
+        ("resize", _) => SubCommand::Resize(parse_resize(args)?),
+        ("scratchpad", ["show"]) => SubCommand::ScratchpadShow,
+        ("shortcuts_inhibitor", [state]) => {
+            SubCommand::ShortcutsInhibitor(from_keyword(state, EN_DISABLE)?)
+        }
+        ("split", [split]) => SubCommand::Split(match *split {
+            "vertical" | "v" => Split::Vertical,
+            "horizontal" | "h" => Split::Horizontal,
+            "none" | "n" => Split::None,
+            "toggle" | "t" => Split::Toggle,
+            _ => return None,
+        }),
+        ("sticky", [state]) => SubCommand::Sticky(from_keyword(state, EN_DIS_TOG)?),
+        ("swap", ["container", "with", kind, id]) => SubCommand::Swap(match *kind {
+            "id" => Swap::Id(id.to_string()),
+            "con_id" => Swap::ConId(id.to_string()),
+            "mark" => Swap::Mark(id.to_string()),
+            _ => return None,
+        }),
+        ("title_format", [_, ..]) => SubCommand::TitleFormat(rest.to_owned()),
+        _ => return None,
+    })
+}
+
+fn parse_border(args: &[&str]) -> Option<Border> {
+    let thickness = |thickness: &[&str]| match thickness {
+        [] => Some(None),
+        [thickness] => thickness.parse().ok().map(Some),
+        _ => None,
+    };
+    Some(match args {
+        ["none"] => Border::None,
+        ["csd"] => Border::ClientSideDecorations,
+        ["toggle"] => Border::Toggle,
+        ["normal", rest @ ..] => Border::Normal(thickness(rest)?),
+        ["pixel", rest @ ..] => Border::Pixel(thickness(rest)?),
+        _ => return None,
+    })
+}
+
+fn parse_focus(args: &[&str]) -> Option<Focus> {
+    Some(match args {
+        [] => Focus::This,
+        ["prev"] => Focus::Prev(false),
+        ["prev", "sibling"] => Focus::Prev(true),
+        ["next"] => Focus::Next(false),
+        ["next", "sibling"] => Focus::Next(true),
+        ["output", output] => Focus::Output(
+            from_keyword(
+                output,
+                [
+                    FocusOutput::Up,
+                    FocusOutput::Right,
+                    FocusOutput::Down,
+                    FocusOutput::Left,
+                ],
+            )
+            .unwrap_or_else(|| FocusOutput::Name(output.to_string())),
+        ),
+        [focus] => from_keyword(
+            focus,
+            [
+                Focus::Up,
+                Focus::Right,
+                Focus::Down,
+                Focus::Left,
+                Focus::Child,
+                Focus::Parent,
+                Focus::Tiling,
+                Focus::Floating,
+                Focus::ModeToggle,
+            ],
+        )?,
+        _ => return None,
+    })
+}
+
+fn parse_layout(args: &[&str]) -> Option<Layout> {
+    Some(match args {
+        ["toggle"] => Layout::Toggle(LayoutToggle::None),
+        ["toggle", "split"] => Layout::Toggle(LayoutToggle::Split),
+        ["toggle", "all"] => Layout::Toggle(LayoutToggle::All),
+        ["toggle", options @ ..] => Layout::Toggle(LayoutToggle::Options(
+            options
+                .iter()
+                .map(|option| {
+                    from_keyword(
+                        option,
+                        [
+                            LayoutToggleOptions::Split,
+                            LayoutToggleOptions::Tabbed,
+                            LayoutToggleOptions::Stacking,
+                            LayoutToggleOptions::Splitv,
+                            LayoutToggleOptions::Splith,
+                        ],
+                    )
+                })
+                .collect::<Option<_>>()?,
+        )),
+        [layout] => from_keyword(
+            layout,
+            [
+                Layout::Default,
+                Layout::Splith,
+                Layout::Splitv,
+                Layout::Stacking,
+                Layout::Tabbed,
+            ],
+        )?,
+        _ => return None,
+    })
+}
+
+fn parse_output(output: &[&str]) -> Option<Output> {
+    Some(match output {
+        [output] => from_keyword(
+            output,
+            [
+                Output::Up,
+                Output::Right,
+                Output::Down,
+                Output::Left,
+                Output::Current,
+            ],
+        )
+        .unwrap_or_else(|| Output::Name(output.to_string())),
+        _ => return None,
+    })
+}
+
+fn parse_move(args: &[&str]) -> Option<Move> {
+    let pixels = |pixels: &[&str]| match pixels {
+        [] => Some(10),
+        [pixels] | [pixels, "px"] => pixels.parse().ok(),
+        _ => None,
+    };
+    Some(match args {
+        ["left", rest @ ..] => Move::Left(pixels(rest)?),
+        ["right", rest @ ..] => Move::Right(pixels(rest)?),
+        ["up", rest @ ..] => Move::Up(pixels(rest)?),
+        ["down", rest @ ..] => Move::Down(pixels(rest)?),
+        ["position", "center"] => Move::PositionCenter,
+        ["position", "cursor" | "mouse"] => Move::PositionCursor,
+        ["absolute", "position", "center"] => Move::AbsolutePositionCenter,
+        ["position", rest @ ..] => {
+            let (x, rest) = parse_length(rest)?;
+            let (y, []) = parse_length(rest)? else {
+                return None;
+            };
+            Move::Position(x, y)
+        }
+        ["absolute", "position", rest @ ..] => {
+            let (Length::Px(x) | Length::Default(x), rest) = parse_length(rest)? else {
+                return None;
+            };
+            let (Length::Px(y) | Length::Default(y), []) = parse_length(rest)? else {
+                return None;
+            };
+            Move::AbsolutePosition(x, y)
+        }
+        ["workspace", "to" | "output", ..] => {
+            let output = args[1..].strip_prefix(&["to"]).unwrap_or(&args[1..]);
+            Move::WorkspaceToOutput(parse_output(output.strip_prefix(&["output"])?)?)
+        }
+        _ => {
+            let (no_auto_back_and_forth, args) =
+                match args.strip_prefix(&["--no-auto-back-and-forth"]) {
+                    Some(args) => (true, args),
+                    None => (false, args),
+                };
+            let args = args
+                .strip_prefix(&["container"])
+                .or_else(|| args.strip_prefix(&["window"]))
+                .unwrap_or(args);
+            let args = args.strip_prefix(&["to"]).unwrap_or(args);
+            match args {
+                ["workspace", workspace @ ..] if !workspace.is_empty() => {
+                    let workspace = workspace.join(" ").parse().ok()?;
+                    if no_auto_back_and_forth {
+                        Move::WorkspaceNoAutoBackAndForth(workspace)
+                    } else {
+                        Move::Workspace(workspace)
+                    }
+                }
+                _ if no_auto_back_and_forth => return None,
+                ["mark", mark] => Move::Mark(mark.to_string()),
+                ["scratchpad"] => Move::Scratchpad,
+                ["output", output @ ..] => Move::ContainerToOutput(parse_output(output)?),
+                _ => return None,
+            }
+        }
+    })
+}
+
+fn parse_resize(args: &[&str]) -> Option<Resize> {
+    let amount = |amount: &[&str]| match amount {
+        [] => Some(Length::Default(10)),
+        amount => match parse_length(amount)? {
+            (length, []) => Some(length),
+            _ => None,
+        },
+    };
+    Some(match args {
+        ["grow", "width" | "w", rest @ ..] => Resize::GrowWidth(amount(rest)?),
+        ["shrink", "width" | "w", rest @ ..] => Resize::ShrinkWidth(amount(rest)?),
+        ["grow", "height" | "h", rest @ ..] => Resize::GrowHeight(amount(rest)?),
+        ["shrink", "height" | "h", rest @ ..] => Resize::ShrinkHeight(amount(rest)?),
+        ["set", "height", rest @ ..] => Resize::SetHeight(amount(rest)?),
+        ["set", "width", rest @ ..] => {
+            let (width, rest) = parse_length(rest)?;
+            match rest {
+                [] => Resize::SetWidth(width),
+                ["height", rest @ ..] => Resize::Set(width, amount(rest)?),
+                _ => return None,
+            }
+        }
+        _ => return None,
+    })
+}
+
+/// Parses a number followed by an optional unit, either separated by a space
+/// or directly attached
+fn parse_length<'a>(args: &'a [&'a str]) -> Option<(Length, &'a [&'a str])> {
+    let (amount, rest) = args.split_first()?;
+    if let Some(px) = amount.strip_suffix("px").filter(|px| !px.is_empty()) {
+        return Some((Length::Px(px.parse().ok()?), rest));
+    }
+    if let Some(ppt) = amount.strip_suffix("ppt").filter(|ppt| !ppt.is_empty()) {
+        return Some((Length::Ppt(ppt.parse().ok()?), rest));
+    }
+    let amount = amount.parse().ok()?;
+    Some(match rest {
+        ["px", rest @ ..] => (Length::Px(amount), rest),
+        ["ppt", rest @ ..] => (Length::Ppt(amount), rest),
+        rest => (Length::Default(amount), rest),
+    })
 }
 
 #[derive(Display, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Border {
     #[display(fmt = "none")]
     None,
     /// A border of thickness n and a title bar
-    #[display(fmt = "normal {}", "to_string_or_empty(_0)")]
+    #[display(fmt = "normal{}", "then_or_empty(_0, |px| format!(\" {px}\"))")]
     Normal(Option<u32>),
     /// Allows the client to draw its own decorations
     #[display(fmt = "csd")]
     ClientSideDecorations,
     /// A border without title bar n pixels thick
-    #[display(fmt = "pixel {}", "to_string_or_empty(_0)")]
+    #[display(fmt = "pixel{}", "then_or_empty(_0, |px| format!(\" {px}\"))")]
     Pixel(Option<u32>),
     /// Cycles through the available border styles
     #[display(fmt = "toggle")]
@@ -164,6 +589,8 @@ pub enum Border {
 }
 
 #[derive(Display, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Focus {
     /// Moves focus to the container that matches the specified criteria
     #[display(fmt = "")]
@@ -180,15 +607,15 @@ pub enum Focus {
     /// Moves focus to the next container in the specified direction.
     #[display(fmt = "left")]
     Left,
-    ///  Moves focus to the previous container in the current layout. Pass
-    /// `true` to focus  the last active child of the newly focused
-    /// container instead of the container it self.
-    #[display(fmt = "prev {}", "when(!_0, \"sibling\")")]
+    /// Moves focus to the previous container in the current layout. With
+    /// `true` (`prev sibling`) the sibling container itself is focused, with
+    /// `false` (`prev`) the last active child of the newly focused container.
+    #[display(fmt = "prev{}", "when(*_0, \" sibling\")")]
     Prev(bool),
-    ///  Moves focus to the next container in the current layout. Pass
-    /// `true` to focus  the last active child of the newly focused
-    /// container instead of the container it self.
-    #[display(fmt = "next {}", "when(!_0, \"sibling\")")]
+    /// Moves focus to the next container in the current layout. With
+    /// `true` (`next sibling`) the sibling container itself is focused, with
+    /// `false` (`next`) the last active child of the newly focused container.
+    #[display(fmt = "next{}", "when(*_0, \" sibling\")")]
     Next(bool),
     /// Moves focus to the last-focused child of the focused container
     #[display(fmt = "child")]
@@ -211,6 +638,8 @@ pub enum Focus {
 }
 
 #[derive(Display, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum FocusOutput {
     /// Next output in the specified direction
     #[display(fmt = "up")]
@@ -229,6 +658,8 @@ pub enum FocusOutput {
 }
 
 #[derive(Display, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum FullscreenGlobal {
     #[display(fmt = "global")]
     Global,
@@ -237,6 +668,8 @@ pub enum FullscreenGlobal {
 }
 
 #[derive(Display, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum GapsWorkspaces {
     #[display(fmt = "all")]
     All,
@@ -244,7 +677,22 @@ pub enum GapsWorkspaces {
     Current,
 }
 
+/// Parses the workspaces case-insensitively
+impl FromStr for GapsWorkspaces {
+    type Err = GapsWorkspacesParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_ascii_lowercase().as_str() {
+            "all" => GapsWorkspaces::All,
+            "current" => GapsWorkspaces::Current,
+            _ => return Err(GapsWorkspacesParseError { value: s.into() }),
+        })
+    }
+}
+
 #[derive(Display, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum GapsModification {
     #[display(fmt = "set")]
     Set,
@@ -256,7 +704,24 @@ pub enum GapsModification {
     Toggle,
 }
 
+/// Parses the modification case-insensitively
+impl FromStr for GapsModification {
+    type Err = GapsModificationParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_ascii_lowercase().as_str() {
+            "set" => GapsModification::Set,
+            "plus" => GapsModification::Plus,
+            "minus" => GapsModification::Minus,
+            "toggle" => GapsModification::Toggle,
+            _ => return Err(GapsModificationParseError { value: s.into() }),
+        })
+    }
+}
+
 #[derive(Display, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum InhibitIdle {
     /// Will inhibit idle when the view is focused by any seat
     #[display(fmt = "focus")]
@@ -269,7 +734,7 @@ pub enum InhibitIdle {
     /// unset/changed)
     #[display(fmt = "open")]
     Open,
-    /// Will inhibit idle when the view is visible on any output
+    /// Removes any existing idle inhibitor for the view
     #[display(fmt = "none")]
     None,
     /// Will inhibit idle when the view is visible on any output
@@ -278,6 +743,8 @@ pub enum InhibitIdle {
 }
 
 #[derive(Display, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Layout {
     #[display(fmt = "default")]
     Default,
@@ -291,13 +758,16 @@ pub enum Layout {
     Tabbed,
     /// Cycles the layout mode of the focused container though a preset list of
     /// layouts.
-    #[display(fmt = "toggle {_0}")]
+    #[display(fmt = "toggle{}{_0}", "when(!matches!(_0, LayoutToggle::None), \" \")")]
     Toggle(LayoutToggle),
 }
 
 #[derive(Display, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum LayoutToggle {
-    /// Cycles through stacking, tabbed and the last split layout.     None,
+    /// Cycles through stacking, tabbed and the last split layout.
+    #[display(fmt = "")]
     None,
     /// Cycles through splith and splitv.
     #[display(fmt = "split")]
@@ -314,7 +784,31 @@ pub enum LayoutToggle {
     Options(Vec<LayoutToggleOptions>),
 }
 
+impl LayoutToggle {
+    /// Iterates the layouts of [`Self::Options`], `None` for the other variants
+    ///
+    /// ```
+    /// # use sway_command::commands::*;
+    /// let toggle = LayoutToggle::Options(vec![LayoutToggleOptions::Tabbed]);
+    /// assert_eq!(toggle.options_iter().map(Iterator::count), Some(1));
+    /// assert!(LayoutToggle::All.options_iter().is_none());
+    /// ```
+    pub fn options_iter(&self) -> Option<std::slice::Iter<'_, LayoutToggleOptions>> {
+        match self {
+            LayoutToggle::Options(options) => Some(options.iter()),
+            _ => None,
+        }
+    }
+
+    /// Whether this is [`Self::Options`]
+    pub fn is_options(&self) -> bool {
+        matches!(self, LayoutToggle::Options(_))
+    }
+}
+
 #[derive(Display, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum LayoutToggleOptions {
     #[display(fmt = "split")]
     Split,
@@ -329,13 +823,35 @@ pub enum LayoutToggleOptions {
 }
 
 #[derive(Display, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum MaxRenderTime {
     #[display(fmt = "off")]
     Off,
+    #[display(fmt = "{_0}")]
     Msec(u32),
 }
 
+impl MaxRenderTime {
+    /// Creates [`MaxRenderTime::Msec`], or [`MaxRenderTime::Off`] if `ms` is 0
+    ///
+    /// ```
+    /// # use sway_command::commands::MaxRenderTime;
+    /// assert!(matches!(MaxRenderTime::from_ms(0), MaxRenderTime::Off));
+    /// assert!(matches!(MaxRenderTime::from_ms(5), MaxRenderTime::Msec(5)));
+    /// ```
+    pub fn from_ms(ms: u32) -> MaxRenderTime {
+        if ms == 0 {
+            MaxRenderTime::Off
+        } else {
+            MaxRenderTime::Msec(ms)
+        }
+    }
+}
+
 #[derive(Display, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Move {
     /// Moves the focused container in the direction specified. Pixels are
     /// ignored when moving tiled containers
@@ -356,11 +872,11 @@ pub enum Move {
     /// Moves the focused container to the specified position in the workspace
     ///
     /// The position can be specified in pixels or percentage points.
-    #[display(fmt = "position {_0} {_0}")]
+    #[display(fmt = "position {_0} {_1}")]
     Position(Length, Length),
     /// Moves the focused container to the specified position relative to all
     /// outputs
-    #[display(fmt = "absolute position {_0} px {_0} px")]
+    #[display(fmt = "absolute position {_0} px {_1} px")]
     AbsolutePosition(u32, u32),
     /// Moves the focused container to be centered on the workspace
     #[display(fmt = "position center")]
@@ -372,7 +888,7 @@ pub enum Move {
     #[display(fmt = "position cursor")]
     PositionCursor,
     /// Moves the focused container to the specified mark
-    #[display(fmt = "container to mark")]
+    #[display(fmt = "container to mark {_0}")]
     Mark(String),
     /// Moves the focused container to the specified workspace
     #[display(fmt = "container to workspace {_0}")]
@@ -391,7 +907,27 @@ pub enum Move {
     WorkspaceToOutput(Output),
 }
 
+impl Move {
+    /// Moves to `x`, `y` in pixels, see [`Self::Position`]
+    pub fn position_px(x: u32, y: u32) -> Move {
+        Move::Position(Length::Px(x), Length::Px(y))
+    }
+
+    /// Moves to `x`, `y` in percentage points, see [`Self::Position`]
+    pub fn position_ppt(x: u32, y: u32) -> Move {
+        Move::Position(Length::Ppt(x), Length::Ppt(y))
+    }
+
+    /// Moves to `x`, `y` in pixels relative to all outputs, see
+    /// [`Self::AbsolutePosition`]
+    pub fn absolute_position_px(x: u32, y: u32) -> Move {
+        Move::AbsolutePosition(x, y)
+    }
+}
+
 #[derive(Display, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Resize {
     /// Resizes the currently focused container by amount, specified in pixels
     /// or percentage points. If the units are omitted, floating containers are
@@ -433,22 +969,30 @@ pub enum Resize {
     /// specified in pixels or percentage points. If the units are omitted,
     /// floating containers are resized in px and tiled containers by ppt. If
     /// width or height is 0, the container will not be resized on that axis.
-    #[display(fmt = "set width {_0} height {_0}")]
+    #[display(fmt = "set width {_0} height {_1}")]
     Set(Length, Length),
 }
 
 #[derive(Display, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Split {
+    #[display(fmt = "vertical")]
     Vertical,
+    #[display(fmt = "horizontal")]
     Horizontal,
     /// The effect of a previous split is undone if the current container is the
     /// only child of a split parent.
+    #[display(fmt = "none")]
     None,
     /// The current container is split opposite to the parent container's layout
+    #[display(fmt = "toggle")]
     Toggle,
 }
 
 #[derive(Display, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Swap {
     /// can only be used with xwayland views
     #[display(fmt = "id {_0}")]
@@ -459,7 +1003,19 @@ pub enum Swap {
     Mark(String),
 }
 
-#[derive(Display, Clone)]
+/// A length in pixels or percentage points
+///
+/// Can be scaled by multiplying or dividing with a `u32`, keeping the unit.
+/// Multiplication saturates and [`Length::Ppt`] is capped at `100`:
+/// ```
+/// # use sway_command::commands::Length;
+/// assert_eq!((Length::Px(10) * 3).to_string(), "30 px");
+/// assert_eq!((Length::Ppt(40) * 3).to_string(), "100 ppt");
+/// assert_eq!((Length::Default(9) / 2).to_string(), "4");
+/// ```
+#[derive(Display, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Length {
     #[display(fmt = "{_0} px")]
     Px(u32),
@@ -468,3 +1024,208 @@ pub enum Length {
     #[display(fmt = "{_0}")]
     Default(u32),
 }
+
+impl Length {
+    fn map(self, f: impl FnOnce(u32) -> u32) -> Self {
+        match self {
+            Length::Px(value) => Length::Px(f(value)),
+            Length::Ppt(value) => Length::Ppt(f(value).min(100)),
+            Length::Default(value) => Length::Default(f(value)),
+        }
+    }
+}
+
+impl Mul<u32> for Length {
+    type Output = Length;
+
+    fn mul(self, rhs: u32) -> Self::Output {
+        self.map(|value| value.saturating_mul(rhs))
+    }
+}
+
+/// # Panics
+/// Panics if `rhs` is `0`
+impl Div<u32> for Length {
+    type Output = Length;
+
+    fn div(self, rhs: u32) -> Self::Output {
+        self.map(|value| value / rhs)
+    }
+}
+
+#[test]
+fn focus_sibling() {
+    assert_eq!(
+        SubCommand::Focus(Focus::Prev(false)).to_string(),
+        "focus prev"
+    );
+    assert_eq!(
+        SubCommand::Focus(Focus::Prev(true)).to_string(),
+        "focus prev sibling"
+    );
+    assert_eq!(
+        SubCommand::Focus(Focus::Next(false)).to_string(),
+        "focus next"
+    );
+    assert_eq!(
+        SubCommand::Focus(Focus::Next(true)).to_string(),
+        "focus next sibling"
+    );
+}
+
+#[test]
+fn layout_toggle() {
+    assert_eq!(
+        SubCommand::Layout(Layout::Toggle(LayoutToggle::None)).to_string(),
+        "layout toggle"
+    );
+    assert_eq!(
+        SubCommand::Layout(Layout::Toggle(LayoutToggle::Split)).to_string(),
+        "layout toggle split"
+    );
+}
+
+#[test]
+fn max_render_time() {
+    assert_eq!(
+        SubCommand::MaxRenderTime(MaxRenderTime::Msec(5)).to_string(),
+        "max_render_time 5"
+    );
+    assert_eq!(MaxRenderTime::Off.to_string(), "off");
+}
+
+#[test]
+fn split() {
+    assert_eq!(
+        SubCommand::Split(Split::Vertical).to_string(),
+        "split vertical"
+    );
+    assert_eq!(
+        SubCommand::Split(Split::Horizontal).to_string(),
+        "split horizontal"
+    );
+    assert_eq!(SubCommand::Split(Split::None).to_string(), "split none");
+    assert_eq!(SubCommand::Split(Split::Toggle).to_string(), "split toggle");
+}
+
+#[test]
+fn fullscreen_global() {
+    assert_eq!(
+        SubCommand::Fullscreen(EnDisTog::Toggle, FullscreenGlobal::No).to_string(),
+        "fullscreen toggle"
+    );
+    assert_eq!(
+        SubCommand::Fullscreen(EnDisTog::Enable, FullscreenGlobal::Global).to_string(),
+        "fullscreen enable global"
+    );
+}
+
+#[test]
+fn length_scaling() {
+    assert!(Length::Px(10) * 3 == Length::Px(30));
+    assert!(Length::Px(u32::MAX) * 2 == Length::Px(u32::MAX));
+    assert!(Length::Ppt(50) * 2 == Length::Ppt(100));
+    assert!(Length::Ppt(50) * 3 == Length::Ppt(100));
+    assert!(Length::Px(10) / 3 == Length::Px(3));
+    assert!(Length::Ppt(100) / 4 == Length::Ppt(25));
+    assert!(Length::Px(10) / 2 != Length::Ppt(5));
+}
+
+#[test]
+fn move_position() {
+    assert!(matches!(
+        Move::position_px(10, 20),
+        Move::Position(Length::Px(10), Length::Px(20))
+    ));
+    assert!(matches!(
+        Move::position_ppt(10, 20),
+        Move::Position(Length::Ppt(10), Length::Ppt(20))
+    ));
+    assert!(matches!(
+        Move::absolute_position_px(10, 20),
+        Move::AbsolutePosition(10, 20)
+    ));
+}
+
+#[test]
+fn parse_gaps() {
+    assert!(matches!("plus".parse(), Ok(GapsModification::Plus)));
+    assert!(matches!("TOGGLE".parse(), Ok(GapsModification::Toggle)));
+    assert!(matches!("Current".parse(), Ok(GapsWorkspaces::Current)));
+    assert!(matches!("all".parse(), Ok(GapsWorkspaces::All)));
+    assert!(matches!("bottom".parse(), Ok(GapsDirection::Bottom)));
+    assert!(matches!(
+        "more".parse::<GapsModification>(),
+        Err(GapsModificationParseError { value }) if value == "more"
+    ));
+    assert!("".parse::<GapsWorkspaces>().is_err());
+}
+
+#[test]
+fn sub_command_from_str() {
+    for command in [
+        "border pixel 2",
+        "border normal",
+        "border csd",
+        "exit",
+        "floating toggle",
+        "focus",
+        "focus prev sibling",
+        "focus output DP-1",
+        "focus mode_toggle",
+        "fullscreen enable global",
+        "gaps outer all plus 5",
+        "inhibit_idle visible",
+        "layout tabbed",
+        "layout toggle",
+        "layout toggle tabbed splith",
+        "max_render_time off",
+        "max_render_time 5",
+        "move left 20 px",
+        "move position 10 ppt 20 px",
+        "move absolute position 10 px 20 px",
+        "move position center",
+        "move container to mark web",
+        "move --no-auto-back-and-forth container to workspace 1:web",
+        "move container to scratchpad",
+        "move container to output left",
+        "move workspace to output DP-1",
+        "nop a comment",
+        "reload",
+        "rename workspace 1 to 2:mail",
+        "rename workspace to mail",
+        "resize set width 400 px height 50 ppt",
+        "resize grow height 10",
+        "scratchpad show",
+        "shortcuts_inhibitor disable",
+        "split toggle",
+        "sticky enable",
+        "swap container with con_id 4",
+        "title_format \"<b>%title</b>\"",
+    ] {
+        assert_eq!(command.parse::<SubCommand>().unwrap().to_string(), command);
+    }
+    for (command, expected) in [
+        ("split v", "split vertical"),
+        (
+            "move window to workspace next",
+            "move container to workspace next",
+        ),
+        ("move up", "move up 10 px"),
+        ("resize shrink width 10px", "resize shrink width 10 px"),
+        ("resize grow w", "resize grow width 10"),
+    ] {
+        assert_eq!(command.parse::<SubCommand>().unwrap().to_string(), expected);
+    }
+    for command in [
+        "",
+        "sticky",
+        "floating on",
+        "move sideways",
+        "resize set width",
+        "swap container with title x",
+        "exec foot",
+    ] {
+        assert!(command.parse::<SubCommand>().is_err(), "{command}");
+    }
+}
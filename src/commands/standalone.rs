@@ -1,22 +1,40 @@
-use std::num::NonZeroU32;
+use std::{
+    fmt,
+    num::NonZeroU32,
+    ops::{BitOr, BitOrAssign},
+    str::FromStr,
+    time::Duration,
+};
 
 use derive_more::Display;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use vec1::Vec1;
 
-use super::{EnDisTog, WorkspaceName, YesNo};
+use super::{
+    from_keyword, EnDisTog, InputDeviceIdentifier, OutputSubcommand, WorkspaceName, YesNo,
+    EN_DIS_TOG, YES_NO,
+};
 use crate::{
     commands::{
-        separated, then_or_empty, to_string_or_empty, when, Font, GapsDirection, Output, Workspace,
+        separated, space_prefixed, then_or_empty, when, Font, GapsDirection, Output, Workspace,
     },
-    criteria::{Criteria, CriteriaList},
+    criteria::{split_criteria, CriteriaList},
+    error::{CommandParseError, SetVariableNameError, TitlebarBorderThicknessError},
     Command,
 };
 
 #[derive(Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum CriterialessCommand {
-    #[display(fmt = "assign {_0} → workspace {_0}")]
+    /// Assigns views matching criteria to workspace, see
+    /// [`CriterialessCommand::assign_to_workspace`]
+    #[display(fmt = "assign {_0} → workspace {_1}")]
     AssignWorkspace(CriteriaList, Workspace),
-    #[display(fmt = "assign {_0} → output {_0}")]
+    /// Assigns views matching criteria to the workspace currently shown on
+    /// output, see [`CriterialessCommand::assign_to_output`]
+    #[display(fmt = "assign {_0} → output {_1}")]
     AssignOutput(CriteriaList, Output),
     /// Binds key combo to execute the sway command command when pressed
     ///
@@ -80,10 +98,10 @@ pub enum CriterialessCommand {
     /// If --whole-window is given, the command can be triggered when the cursor
     /// is over an empty workspace. Using a mouse binding over a layer
     /// surface's exclusive region is not currently possible.
-    #[display(fmt = "bindsym {_0} {_1} {_2}")]
+    #[display(fmt = "bindsym {_0}{}{_1} {_2}", "when(!_0.is_empty(), \" \")")]
     Bindsym(BindFlags, SymKey, Command),
     /// Like [`CriterialessCommand::Bindsym`] but for key/button codes
-    #[display(fmt = "bindcode {_0} {_1} {_2}")]
+    #[display(fmt = "bindcode {_0}{}{_1} {_2}", "when(!_0.is_empty(), \" \")")]
     Bindcode(BindFlags, SymCode, Command),
     /// Binds <switch> to execute the sway command command on state changes
     ///
@@ -106,7 +124,7 @@ pub enum CriterialessCommand {
     ///
     /// By default, if you overwrite a binding, swaynag will give you a warning.
     /// To silence this, use the --no-warn flag.
-    #[display(fmt = "bindswitch {_0} {_1}:{_2} {_3}")]
+    #[display(fmt = "bindswitch {_0}{}{_1}:{_2} {_3}", "when(!_0.is_empty(), \" \")")]
     Bindswitch(BindswitchFlags, Switch, SwitchState, Command),
     /// This command is ignored and is only present for i3 compatibility.
     // TODO feature for i3 things
@@ -146,8 +164,12 @@ pub enum CriterialessCommand {
     /// When the modifier key is held down, you may hold left click to move
     /// windows, and right click to resize them.
     ///
-    /// Setting modifier to none disables this feature.
-    #[display(fmt = "floating_modifier {} x {_1}", "to_string_or_empty(_0)")]
+    /// Setting modifier to none disables this feature, the mode is ignored in
+    /// that case.
+    #[display(
+        fmt = "floating_modifier {}",
+        "_0.as_ref().map_or_else(|| \"none\".to_owned(), |modifier| format!(\"{modifier} {_1}\"))"
+    )]
     FloatingModifier(Option<String>, FloatingModifierMode),
     /// If set to yes, moving your mouse over a window will focus that window.
     /// If set to always, the window under the cursor will always be focused,
@@ -193,11 +215,23 @@ pub enum CriterialessCommand {
     /// above and below text). Padding includes titlebar borders so their value
     /// should be greater than titlebar_border_thickness. If vertical value is
     /// not specified it is set to the horizon‐ tal value.
-    #[display(fmt = "titlebar_padding {_0} {}", "to_string_or_empty(_1)")]
+    #[display(
+        fmt = "titlebar_padding {_0}{}",
+        "then_or_empty(_1, |vertical| format!(\" {vertical}\"))"
+    )]
     TitlebarPadding(NonZeroU32, Option<NonZeroU32>),
     /// Whenever a window that matches criteria appears, run list of commands.
+    ///
+    /// ```
+    /// # use sway_command::{commands::*, criteria::CriteriaList};
+    /// let cmd = CriterialessCommand::ForWindow(
+    ///     CriteriaList::app_id("foot"),
+    ///     SubCommand::Border(Border::None).into(),
+    /// );
+    /// assert_eq!(cmd.to_string(), "for_window [app_id=\"foot\"] border none");
+    /// ```
     #[display(fmt = "for_window {_0} {_1}")]
-    ForWindow(Criteria, Command),
+    ForWindow(CriteriaList, Command),
     ///  Sets default amount pixels of inner or outer gap, where the inner
     /// affects spacing around each view and outer affects the spacing around
     /// each workspace. Outer gaps are in addition to inner gaps. To reduce or
@@ -225,11 +259,11 @@ pub enum CriterialessCommand {
     /// `*` may be used in lieu of a specific device name to configure all input
     /// devices. A list of input device names may be obtained via swaymsg -t
     /// get_inputs.
-    #[display(fmt = "input {_0} {}", "separated(_1, ' ')")]
-    Input(String, Vec<String>),
+    #[display(fmt = "input {_0}{}", "space_prefixed(_1)")]
+    Input(InputDeviceIdentifier, Vec<String>),
     /// For details on seat subcommands, see sway-input(5)
-    #[display(fmt = "seat {_0} {}", "separated(_1, ' ')")]
-    Seat(String, Vec<String>),
+    #[display(fmt = "seat {_0}{}", "space_prefixed(_1)")]
+    Seat(InputDeviceIdentifier, Vec<String>),
     /// Kills (closes) the currently focused container and all of its children
     #[display(fmt = "kill")]
     Kill,
@@ -244,15 +278,15 @@ pub enum CriterialessCommand {
     /// Switches to the specified mode
     ///
     /// The default mode is default.
-    #[display(fmt = "mode")]
+    #[display(fmt = "mode {_0}")]
     Mode(String),
     /// The only valid mode-subcommands... are bindsym, bindcode, bindswitch,
     /// and set.
-    #[display(fmt = "mode {_0} {}", "separated(_1, ' ')")]
+    #[display(fmt = "mode {_0}{}", "space_prefixed(_1)")]
     ModeCmds(String, Vec<String>),
     /// The only valid mode-subcommands... are bindsym, bindcode, bindswitch,
     /// and set. Mode will be interpreted as pango markup.
-    #[display(fmt = "mode --pango_markup {_0} {}", "separated(_1, ' ')")]
+    #[display(fmt = "mode --pango_markup {_0}{}", "space_prefixed(_1)")]
     ModePangoMarkupCmds(String, Vec<String>),
     /// If output is specified, the mouse will be moved to new outputs as you
     /// move focus between them. If container is specified, the mouse will be
@@ -264,14 +298,14 @@ pub enum CriterialessCommand {
     ///
     /// This has no effect on the first window in a workspace.
     #[display(fmt = "no_focus {_0}")]
-    NoFocus(Criteria),
+    NoFocus(CriteriaList),
     /// For details on output subcommands, see sway-output(5)
     ///
     /// `*` may be used in lieu of a specific output name to configure all
     /// outputs. A list of output names may be obtained via swaymsg -t
     /// get_outputs.
-    #[display(fmt = "output {_0} {}", "separated(_1, ' ')")]
-    Output(String, Vec<String>),
+    #[display(fmt = "output {_0}{}", "space_prefixed(_1)")]
+    Output(String, Vec<OutputSubcommand>),
     /// Determines what to do when a fullscreen view opens a dialog
     ///
     /// If smart (the default), the dialog will be dis‐ played. If ignore, the
@@ -333,11 +367,11 @@ pub enum CriterialessCommand {
     ///
     /// If input-device is given, only the binding for that input device will be
     /// unbound.
-    #[display(fmt = "unbindsym {_0} {_1}")]
+    #[display(fmt = "unbindsym {_0}{}{_1}", "when(!_0.is_empty(), \" \")")]
     Unbindsym(BindFlags, SymKey),
     /// <code> is also available for unbinding with key/button codes instead of
     /// key/button names
-    #[display(fmt = "unbindcode {_0} {_1}")]
+    #[display(fmt = "unbindcode {_0}{}{_1}", "when(!_0.is_empty(), \" \")")]
     Unbindcode(BindFlags, SymCode),
     // TODO should this not be in `runtime`
     /// Will remove identifier from the list of current marks on a window
@@ -349,7 +383,7 @@ pub enum CriterialessCommand {
     /// Using enable or disable manually sets or unsets the window's urgent
     /// state. Using allow or deny controls the window's ability to set itself
     /// as urgent. By default, windows are allowed to set their own urgency.
-    #[display(fmt = "unmark {_0}")]
+    #[display(fmt = "urgent {_0}")]
     Urgent(Urgent),
     /// Switches to the specified workspace
     #[display(fmt = "workspace {_0}")]
@@ -372,7 +406,7 @@ pub enum CriterialessCommand {
     /// criteria (non-empty workspaces only) or workspace command (to switch to
     /// the workspace before moving).
     #[display(fmt = "workspace {_0} output {}", "separated(_1, ' ')")]
-    WorkspaceOutput(WorkspaceName, Vec1<String>),
+    WorkspaceOutput(WorkspaceName, Vec1<Output>),
     /// When yes, repeating a workspace switch command will switch back to the
     /// prior workspace. For example, if you are currently on workspace 1,
     /// switch to workspace 2, then invoke the workspace 2 command again, you
@@ -381,20 +415,584 @@ pub enum CriterialessCommand {
     WorkspaceAutoBackAndForth(YesNo),
 }
 
-#[derive(Display, Default)]
-#[display(
-    fmt = "{} {} {} {} {} {} {} {} {} {}",
-    "when(*whole_window, \"--whole-window\")",
-    "when(*border, \"--border\")",
-    "when(*exclude_title_bar, \"--exclude-title-bar\")",
-    "when(*release, \"--release\")",
-    "when(*locked, \"--locked\")",
-    "when(*to_code, \"--to-code\")",
-    "input_device.as_ref().map(|input_device| format!(\"--input-device={}\", input_device)).unwrap_or_default()",
-    "when(*no_warn, \"--no-warn\")",
-    "when(*no_repeat, \"--no-repeat\")",
-    "when(*inhibited, \"--inhibited\")"
-)]
+/// Switches to the workspace, see [`CriterialessCommand::Workspace`]
+///
+/// ```
+/// # use sway_command::{*, commands::*};
+/// let cmd = CommandList::default().command(Workspace::BackAndForth);
+/// assert!(cmd == "workspace back_and_forth");
+/// ```
+impl From<Workspace> for CriterialessCommand {
+    fn from(workspace: Workspace) -> Self {
+        CriterialessCommand::Workspace(workspace)
+    }
+}
+
+impl From<Workspace> for Command {
+    fn from(workspace: Workspace) -> Self {
+        CriterialessCommand::from(workspace).into()
+    }
+}
+
+impl CriterialessCommand {
+    /// Assigns views matching `criteria` to `workspace`, see
+    /// [`Self::AssignWorkspace`]
+    ///
+    /// This is the primary way of placing windows automatically:
+    /// ```
+    /// # use sway_command::{commands::*, criteria::*};
+    /// let cmd = CriterialessCommand::assign_to_workspace(
+    ///     CriteriaList::app_id("firefox"),
+    ///     Workspace::Next,
+    /// );
+    /// assert_eq!(cmd.to_string(), r#"assign [app_id="firefox"] → workspace next"#);
+    /// ```
+    pub fn assign_to_workspace(
+        criteria: CriteriaList,
+        workspace: Workspace,
+    ) -> CriterialessCommand {
+        CriterialessCommand::AssignWorkspace(criteria, workspace)
+    }
+
+    /// Assigns views matching `criteria` to the workspace shown on `output`,
+    /// see [`Self::AssignOutput`]
+    ///
+    /// ```
+    /// # use sway_command::{commands::*, criteria::*};
+    /// let cmd = CriterialessCommand::assign_to_output(
+    ///     CriteriaList::class("Steam"),
+    ///     Output::Name("HDMI-A-1".into()),
+    /// );
+    /// assert_eq!(cmd.to_string(), r#"assign [class="Steam"] → output HDMI-A-1"#);
+    /// ```
+    pub fn assign_to_output(criteria: CriteriaList, output: Output) -> CriterialessCommand {
+        CriterialessCommand::AssignOutput(criteria, output)
+    }
+
+    /// Sets the default gaps in `direction` for new workspaces, see
+    /// [`Self::Gaps`]
+    ///
+    /// Unlike the runtime [`SubCommand::Gaps`](super::SubCommand::Gaps), this
+    /// does not change existing workspaces, making it the usual choice in a
+    /// config file.
+    ///
+    /// ```
+    /// # use sway_command::commands::*;
+    /// assert_eq!(
+    ///     CriterialessCommand::default_gaps(GapsDirection::Top, 5).to_string(),
+    ///     "gaps top 5"
+    /// );
+    /// ```
+    pub fn default_gaps(direction: GapsDirection, amount: u32) -> CriterialessCommand {
+        CriterialessCommand::Gaps(direction, amount)
+    }
+
+    /// Sets the default inner gaps for new workspaces, see
+    /// [`Self::default_gaps`]
+    pub fn default_inner_gaps(amount: u32) -> CriterialessCommand {
+        Self::default_gaps(GapsDirection::Inner, amount)
+    }
+
+    /// Sets the default outer gaps for new workspaces, see
+    /// [`Self::default_gaps`]
+    pub fn default_outer_gaps(amount: u32) -> CriterialessCommand {
+        Self::default_gaps(GapsDirection::Outer, amount)
+    }
+
+    /// Disables moving and resizing windows with a modifier, see
+    /// [`Self::FloatingModifier`]
+    ///
+    /// ```
+    /// # use sway_command::commands::*;
+    /// assert_eq!(
+    ///     CriterialessCommand::floating_modifier_none().to_string(),
+    ///     "floating_modifier none"
+    /// );
+    /// ```
+    pub fn floating_modifier_none() -> CriterialessCommand {
+        CriterialessCommand::FloatingModifier(None, FloatingModifierMode::Normal)
+    }
+
+    /// Moves and resizes windows while holding the super key (`Mod4`), see
+    /// [`Self::FloatingModifier`]
+    ///
+    /// ```
+    /// # use sway_command::commands::*;
+    /// assert_eq!(
+    ///     CriterialessCommand::floating_modifier_super().to_string(),
+    ///     "floating_modifier Mod4 normal"
+    /// );
+    /// ```
+    pub fn floating_modifier_super() -> CriterialessCommand {
+        CriterialessCommand::FloatingModifier(
+            Some("Mod4".to_string()),
+            FloatingModifierMode::Normal,
+        )
+    }
+
+    /// Sets the variable `$name` to `value`, see [`Self::Set`]
+    ///
+    /// `name` may start with a `$`, the rest has to match
+    /// `[a-zA-Z_][a-zA-Z0-9_]*`.
+    ///
+    /// ```
+    /// # use sway_command::commands::*;
+    /// let set = CriterialessCommand::try_set("$mod", "Mod4")?;
+    /// assert_eq!(set.to_string(), "set $mod Mod4");
+    /// assert!(CriterialessCommand::try_set("my var", "value").is_err());
+    /// # Ok::<(), sway_command::error::SetVariableNameError>(())
+    /// ```
+    pub fn try_set(name: &str, value: &str) -> Result<CriterialessCommand, SetVariableNameError> {
+        let stripped = name.strip_prefix('$').unwrap_or(name);
+        let mut chars = stripped.chars();
+        if chars
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            Ok(CriterialessCommand::Set(
+                stripped.to_owned(),
+                value.to_owned(),
+            ))
+        } else {
+            Err(SetVariableNameError {
+                name: name.to_owned(),
+            })
+        }
+    }
+
+    /// Delays resetting the urgency hint of a focused window by `ms`
+    /// milliseconds, see [`Self::ForceDisplayUrgencyHint`]
+    ///
+    /// ```
+    /// # use sway_command::commands::*;
+    /// assert_eq!(
+    ///     CriterialessCommand::force_display_urgency_hint(500).to_string(),
+    ///     "force_display_urgency_hint 500 ms"
+    /// );
+    /// ```
+    pub fn force_display_urgency_hint(ms: u32) -> CriterialessCommand {
+        CriterialessCommand::ForceDisplayUrgencyHint(ms)
+    }
+
+    /// Like [`Self::force_display_urgency_hint`] but taking a [`Duration`],
+    /// saturating at [`u32::MAX`] milliseconds
+    ///
+    /// ```
+    /// # use sway_command::commands::*;
+    /// # use std::time::Duration;
+    /// assert_eq!(
+    ///     CriterialessCommand::force_display_urgency_hint_duration(Duration::from_secs(1))
+    ///         .to_string(),
+    ///     "force_display_urgency_hint 1000 ms"
+    /// );
+    /// ```
+    pub fn force_display_urgency_hint_duration(duration: Duration) -> CriterialessCommand {
+        Self::force_display_urgency_hint(duration.as_millis().try_into().unwrap_or(u32::MAX))
+    }
+
+    /// Sets the thickness of the titlebar border to `px` pixels, see
+    /// [`Self::TitlebarBorderThickness`]
+    ///
+    /// `0` removes the titlebar border. As the border is part of the
+    /// [`titlebar_padding`](Self::titlebar_padding), the padding should be
+    /// larger than the thickness. Use
+    /// [`try_titlebar_border_thickness`](Self::try_titlebar_border_thickness)
+    /// to reject accidentally large values.
+    pub fn titlebar_border_thickness(px: u32) -> CriterialessCommand {
+        CriterialessCommand::TitlebarBorderThickness(px)
+    }
+
+    /// Like [`Self::titlebar_border_thickness`] but rejects thicknesses of
+    /// 100 pixels or more
+    ///
+    /// ```
+    /// # use sway_command::commands::*;
+    /// assert_eq!(
+    ///     CriterialessCommand::try_titlebar_border_thickness(2)?.to_string(),
+    ///     "titlebar_border_thickness 2"
+    /// );
+    /// assert!(CriterialessCommand::try_titlebar_border_thickness(100).is_err());
+    /// # Ok::<(), sway_command::error::TitlebarBorderThicknessError>(())
+    /// ```
+    pub fn try_titlebar_border_thickness(
+        px: u32,
+    ) -> Result<CriterialessCommand, TitlebarBorderThicknessError> {
+        if px < 100 {
+            Ok(Self::titlebar_border_thickness(px))
+        } else {
+            Err(TitlebarBorderThicknessError { px })
+        }
+    }
+
+    /// Sets the padding of the titlebar text, see [`Self::TitlebarPadding`]
+    ///
+    /// The padding includes the
+    /// [`titlebar_border_thickness`](Self::titlebar_border_thickness). Without
+    /// `vertical`, the `horizontal` value is used for both.
+    ///
+    /// ```
+    /// # use sway_command::commands::*;
+    /// # use std::num::NonZeroU32;
+    /// let padding = CriterialessCommand::titlebar_padding(NonZeroU32::new(5).unwrap(), None);
+    /// assert_eq!(padding.to_string(), "titlebar_padding 5");
+    /// ```
+    pub fn titlebar_padding(
+        horizontal: NonZeroU32,
+        vertical: Option<NonZeroU32>,
+    ) -> CriterialessCommand {
+        CriterialessCommand::TitlebarPadding(horizontal, vertical)
+    }
+
+    /// Sets the gaps in `direction` for the workspace `name` once it is
+    /// created, see [`Self::WorkspaceGaps`]
+    ///
+    /// ```
+    /// # use sway_command::commands::*;
+    /// assert_eq!(
+    ///     CriterialessCommand::workspace_gaps("mail", GapsDirection::Left, 5).to_string(),
+    ///     "workspace mail gaps left 5"
+    /// );
+    /// ```
+    pub fn workspace_gaps(
+        name: impl Into<String>,
+        direction: GapsDirection,
+        amount: u32,
+    ) -> CriterialessCommand {
+        CriterialessCommand::WorkspaceGaps(WorkspaceName::Simple(name.into()), direction, amount)
+    }
+
+    /// Sets the inner gaps for the workspace `name` once it is created, see
+    /// [`Self::workspace_gaps`]
+    pub fn workspace_inner_gaps(name: impl Into<String>, amount: u32) -> CriterialessCommand {
+        Self::workspace_gaps(name, GapsDirection::Inner, amount)
+    }
+
+    /// Sets the outer gaps for the workspace `name` once it is created, see
+    /// [`Self::workspace_gaps`]
+    pub fn workspace_outer_gaps(name: impl Into<String>, amount: u32) -> CriterialessCommand {
+        Self::workspace_gaps(name, GapsDirection::Outer, amount)
+    }
+
+    /// Sets the gaps in `direction` for the workspace with number `number` once
+    /// it is created, see [`Self::WorkspaceGaps`]
+    ///
+    /// ```
+    /// # use sway_command::commands::*;
+    /// assert_eq!(
+    ///     CriterialessCommand::workspace_gaps_for_number(3, GapsDirection::Inner, 10)
+    ///         .to_string(),
+    ///     "workspace 3 gaps inner 10"
+    /// );
+    /// ```
+    pub fn workspace_gaps_for_number(
+        number: u32,
+        direction: GapsDirection,
+        amount: u32,
+    ) -> CriterialessCommand {
+        Self::workspace_gaps(number.to_string(), direction, amount)
+    }
+
+    /// Shows the workspace `name` on `output` once it is created, see
+    /// [`Self::WorkspaceOutput`]
+    ///
+    /// ```
+    /// # use sway_command::commands::*;
+    /// assert_eq!(
+    ///     CriterialessCommand::workspace_output(
+    ///         WorkspaceName::WithNumber(1, "web".into()),
+    ///         Output::Name("DP-1".into())
+    ///     )
+    ///     .to_string(),
+    ///     "workspace 1:web output DP-1"
+    /// );
+    /// ```
+    pub fn workspace_output(name: WorkspaceName, output: Output) -> CriterialessCommand {
+        CriterialessCommand::WorkspaceOutput(name, Vec1::new(output))
+    }
+
+    /// Shows the workspace `name` on the first available of `outputs` once it
+    /// is created, see [`Self::WorkspaceOutput`]
+    pub fn workspace_outputs(name: WorkspaceName, outputs: Vec1<Output>) -> CriterialessCommand {
+        CriterialessCommand::WorkspaceOutput(name, outputs)
+    }
+
+    /// Configures the colors of `class`, optional colors can be set on the
+    /// returned [`ClientClassBuilder`], see [`Self::Client`]
+    ///
+    /// ```
+    /// # use sway_command::commands::*;
+    /// let focused = CriterialessCommand::client_class(
+    ///     Class::Focused,
+    ///     Color::rgb(0x44, 0x55, 0x66),
+    ///     Color::rgb(0x28, 0x5D, 0x77),
+    ///     Color::rgb(0xFF, 0xFF, 0xFF),
+    /// )
+    /// .indicator(Color::rgb(0x2E, 0x9E, 0xF4))
+    /// .into_command();
+    /// assert_eq!(
+    ///     focused.to_string(),
+    ///     "client.focused #445566 #285D77 #FFFFFF #2E9EF4"
+    /// );
+    /// ```
+    pub fn client_class(
+        class: Class,
+        border: Color,
+        background: Color,
+        text: Color,
+    ) -> ClientClassBuilder {
+        ClientClassBuilder(ClientClass {
+            class,
+            border,
+            background,
+            text,
+            indicator: None,
+            child_border: None,
+        })
+    }
+
+    /// Runs `command` when `switch` changes to `state`, using the default
+    /// flags, see [`Self::Bindswitch`]
+    ///
+    /// ```
+    /// # use sway_command::{commands::*, Command};
+    /// let command = CriterialessCommand::bindswitch(
+    ///     Switch::Lid,
+    ///     SwitchState::Off,
+    ///     CriterialessCommand::Exec("swaylock".into()),
+    /// );
+    /// assert_eq!(command.to_string(), "bindswitch lid:off exec swaylock");
+    /// ```
+    pub fn bindswitch(
+        switch: Switch,
+        state: SwitchState,
+        command: impl Into<Command>,
+    ) -> CriterialessCommand {
+        CriterialessCommand::Bindswitch(Default::default(), switch, state, command.into())
+    }
+
+    /// Runs `command` when the laptop lid is closed (`lid:on`), see
+    /// [`Self::bindswitch`]
+    pub fn bindswitch_lid_close(command: impl Into<Command>) -> CriterialessCommand {
+        Self::bindswitch(Switch::Lid, SwitchState::On, command)
+    }
+
+    /// Runs `command` when tablet mode is entered (`tablet:on`), see
+    /// [`Self::bindswitch`]
+    pub fn bindswitch_tablet_mode(command: impl Into<Command>) -> CriterialessCommand {
+        Self::bindswitch(Switch::Tablet, SwitchState::On, command)
+    }
+}
+
+/// Parses a command as it is displayed, e.g. `exec foot` or
+/// `for_window [app_id="mpv"] inhibit_idle fullscreen`
+///
+/// Not every command can be parsed yet, unsupported commands return an error.
+/// [`Command::parse`] falls back to [`Command::Raw`](crate::Command::Raw) for
+/// those.
+///
+/// ```
+/// # use sway_command::commands::*;
+/// let command: CriterialessCommand = "workspace 2:mail gaps inner 4".parse()?;
+/// assert_eq!(command.to_string(), "workspace 2:mail gaps inner 4");
+/// let command: CriterialessCommand = "mark --add --toggle web".parse()?;
+/// assert_eq!(command.to_string(), "mark --add --toggle web");
+/// assert!("bindsym $mod+Return exec foot".parse::<CriterialessCommand>().is_err());
+/// # Ok::<(), sway_command::error::CommandParseError>(())
+/// ```
+impl FromStr for CriterialessCommand {
+    type Err = CommandParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (name, rest) = s
+            .split_once(char::is_whitespace)
+            .map_or((s, ""), |(name, rest)| (name, rest.trim()));
+        let args: Vec<&str> = rest.split_whitespace().collect();
+        parse_criterialess_command(name, rest, &args).ok_or_else(|| CommandParseError {
+            command: s.to_owned(),
+        })
+    }
+}
+
+fn parse_criterialess_command(
+    name: &str,
+    rest: &str,
+    args: &[&str],
+) -> Option<CriterialessCommand> {
+    use CriterialessCommand as C;
+    Some(match (name, args) {
+        ("assign", _) => {
+            let (criteria, target) = split_criteria(rest)?;
+            let criteria = criteria.parse().ok()?;
+            let target = target.trim_start();
+            let target = target.strip_prefix('→').unwrap_or(target).trim_start();
+            match target.strip_prefix("output ") {
+                Some(output) => {
+                    let output = output.trim();
+                    C::AssignOutput(
+                        criteria,
+                        from_keyword(
+                            output,
+                            [
+                                Output::Up,
+                                Output::Right,
+                                Output::Down,
+                                Output::Left,
+                                Output::Current,
+                            ],
+                        )
+                        .unwrap_or_else(|| Output::Name(output.to_owned())),
+                    )
+                }
+                None => {
+                    let workspace = target.strip_prefix("workspace ").unwrap_or(target);
+                    C::AssignWorkspace(criteria, workspace.parse().ok()?)
+                }
+            }
+        }
+        ("exec", [_, ..]) => C::Exec(rest.to_owned()),
+        ("exec_always", [_, ..]) => C::ExecAlways(rest.to_owned()),
+        ("floating_maximum_size", [width, "x", height]) => {
+            C::FloatingMaximumSize(width.parse().ok()?, height.parse().ok()?)
+        }
+        ("floating_minimum_size", [width, "x", height]) => {
+            C::FloatingMinimumSize(width.parse().ok()?, height.parse().ok()?)
+        }
+        ("focus_follows_mouse", [focus]) => C::FocusFollowsMouse(from_keyword(
+            focus,
+            [MouseFocus::Yes, MouseFocus::No, MouseFocus::Always],
+        )?),
+        ("focus_on_window_activation", [focus]) => C::FocusOnWindowActivation(from_keyword(
+            focus,
+            [
+                WindowActivationFocus::Smart,
+                WindowActivationFocus::Urgent,
+                WindowActivationFocus::Focus,
+                WindowActivationFocus::None,
+            ],
+        )?),
+        ("focus_wrapping", [wrapping]) => C::FocusWrapping(from_keyword(
+            wrapping,
+            [
+                FocusWrapping::Yes,
+                FocusWrapping::No,
+                FocusWrapping::Force,
+                FocusWrapping::Workspace,
+            ],
+        )?),
+        ("for_window", _) => {
+            let (criteria, command) = split_criteria(rest)?;
+            C::ForWindow(criteria.parse().ok()?, Command::parse(command))
+        }
+        ("force_display_urgency_hint", [ms] | [ms, "ms"]) => {
+            C::ForceDisplayUrgencyHint(ms.parse().ok()?)
+        }
+        ("gaps", [direction, amount]) => C::Gaps(direction.parse().ok()?, amount.parse().ok()?),
+        ("kill", []) => C::Kill,
+        ("mark", [flags @ .., identifier]) => C::Mark(
+            match flags {
+                [] | ["--replace"] => MarkModification::Replace,
+                ["--add"] => MarkModification::Add,
+                ["--toggle"] | ["--replace", "--toggle"] | ["--toggle", "--replace"] => {
+                    MarkModification::ReplaceToggle
+                }
+                ["--add", "--toggle"] | ["--toggle", "--add"] => MarkModification::AddToggle,
+                _ => return None,
+            },
+            identifier.to_string(),
+        ),
+        ("mode", [mode]) => C::Mode(mode.to_string()),
+        ("mouse_warping", [warping]) => C::MouseWarping(from_keyword(
+            warping,
+            [
+                MouseWarping::Output,
+                MouseWarping::Container,
+                MouseWarping::None,
+            ],
+        )?),
+        ("no_focus", _) => match split_criteria(rest)? {
+            (criteria, "") => C::NoFocus(criteria.parse().ok()?),
+            _ => return None,
+        },
+        ("popup_during_fullscreen", [popup]) => C::PopupDuringFullscreen(from_keyword(
+            popup,
+            [
+                PopupDuringFullscreen::Smart,
+                PopupDuringFullscreen::Ignore,
+                PopupDuringFullscreen::LeaveFullscreen,
+            ],
+        )?),
+        ("set", [variable, _, ..]) => {
+            let value = rest.strip_prefix(variable)?.trim_start();
+            C::try_set(variable.strip_prefix('$')?, value).ok()?
+        }
+        ("show_marks", [show]) => C::ShowMarks(from_keyword(show, YES_NO)?),
+        ("smart_borders", [borders]) => C::SmartBorders(from_keyword(
+            borders,
+            [SmartBorders::On, SmartBorders::NoGaps, SmartBorders::Off],
+        )?),
+        ("smart_gaps", [gaps]) => C::SmartGaps(from_keyword(
+            gaps,
+            [
+                SmartGaps::On,
+                SmartGaps::Off,
+                SmartGaps::Toggle,
+                SmartGaps::InverseOuter,
+            ],
+        )?),
+        ("tiling_drag", [state]) => C::TilingDrag(from_keyword(state, EN_DIS_TOG)?),
+        ("tiling_drag_threshold", [threshold]) => C::TilingDragThreshold(threshold.parse().ok()?),
+        ("title_align", [align]) => C::TitleAlign(from_keyword(
+            align,
+            [TitleAlign::Left, TitleAlign::Center, TitleAlign::Right],
+        )?),
+        ("titlebar_border_thickness", [thickness]) => {
+            C::TitlebarBorderThickness(thickness.parse().ok()?)
+        }
+        ("titlebar_padding", [horizontal]) => C::TitlebarPadding(horizontal.parse().ok()?, None),
+        ("titlebar_padding", [horizontal, vertical]) => {
+            C::TitlebarPadding(horizontal.parse().ok()?, Some(vertical.parse().ok()?))
+        }
+        ("unmark", [identifier]) => C::Unmark(identifier.to_string()),
+        ("urgent", [urgent]) => C::Urgent(from_keyword(
+            urgent,
+            [Urgent::Enable, Urgent::Disable, Urgent::Allow, Urgent::Deny],
+        )?),
+        ("workspace", [name @ .., "gaps", direction, amount]) if !name.is_empty() => {
+            C::WorkspaceGaps(
+                name.join(" ").parse().ok()?,
+                direction.parse().ok()?,
+                amount.parse().ok()?,
+            )
+        }
+        // Other flags and `workspace <name> output <outputs>` are not supported yet
+        ("workspace", [first, ..]) if !first.starts_with("--") && !args.contains(&"output") => {
+            C::Workspace(rest.parse().ok()?)
+        }
+        ("workspace_auto_back_and_forth", [back_and_forth]) => {
+            C::WorkspaceAutoBackAndForth(from_keyword(back_and_forth, YES_NO)?)
+        }
+        _ => return None,
+    })
+}
+
+/// Flags of [`CriterialessCommand::Bindsym`] and
+/// [`CriterialessCommand::Bindcode`]
+///
+/// Displays only the set flags separated by a space, the input device last:
+/// ```
+/// # use sway_command::commands::*;
+/// let flags = BindFlags {
+///     release: true,
+///     input_device: Some("1:1:keyboard".into()),
+///     ..Default::default()
+/// };
+/// assert_eq!(flags.to_string(), "--release --input-device=1:1:keyboard");
+/// assert_eq!(BindFlags::default().to_string(), "");
+/// ```
+#[derive(Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BindFlags {
     /// The cursor can be anywhere over a window including the title, border,
     /// and content
@@ -432,8 +1030,105 @@ pub struct BindFlags {
     pub inhibited: bool,
 }
 
-#[derive(Display)]
-#[display(fmt = "{group}{modifiers}{key}")]
+impl BindFlags {
+    /// Returns `true` if no flag is set and no input device is given
+    pub fn is_empty(&self) -> bool {
+        self.input_device.is_none() && self.active().iter().all(Option::is_none)
+    }
+
+    fn active(&self) -> [Option<&'static str>; 9] {
+        [
+            self.whole_window.then_some("--whole-window"),
+            self.border.then_some("--border"),
+            self.exclude_title_bar.then_some("--exclude-title-bar"),
+            self.release.then_some("--release"),
+            self.locked.then_some("--locked"),
+            self.to_code.then_some("--to-code"),
+            self.no_warn.then_some("--no-warn"),
+            self.no_repeat.then_some("--no-repeat"),
+            self.inhibited.then_some("--inhibited"),
+        ]
+    }
+}
+
+impl fmt::Display for BindFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let input_device = self
+            .input_device
+            .as_ref()
+            .map(|input_device| format!("--input-device={input_device}"));
+        let flags = self.active().into_iter().flatten().map(String::from);
+        write!(f, "{}", separated(flags.chain(input_device), ' '))
+    }
+}
+
+/// Union of both flags, the [`BindFlags::input_device`] of the left side takes
+/// precedence
+///
+/// ```
+/// # use sway_command::commands::BindFlags;
+/// let flags = BindFlags {
+///     release: true,
+///     ..Default::default()
+/// } | BindFlags {
+///     locked: true,
+///     ..Default::default()
+/// };
+/// assert!(flags.release && flags.locked);
+/// ```
+impl BitOr for BindFlags {
+    type Output = BindFlags;
+
+    fn bitor(mut self, rhs: BindFlags) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+
+impl BitOrAssign for BindFlags {
+    fn bitor_assign(&mut self, rhs: BindFlags) {
+        let BindFlags {
+            whole_window,
+            border,
+            exclude_title_bar,
+            release,
+            locked,
+            to_code,
+            input_device,
+            no_warn,
+            no_repeat,
+            inhibited,
+        } = rhs;
+        self.whole_window |= whole_window;
+        self.border |= border;
+        self.exclude_title_bar |= exclude_title_bar;
+        self.release |= release;
+        self.locked |= locked;
+        self.to_code |= to_code;
+        if self.input_device.is_none() {
+            self.input_device = input_device;
+        }
+        self.no_warn |= no_warn;
+        self.no_repeat |= no_repeat;
+        self.inhibited |= inhibited;
+    }
+}
+
+impl Extend<BindFlags> for BindFlags {
+    fn extend<T: IntoIterator<Item = BindFlags>>(&mut self, iter: T) {
+        for flags in iter {
+            *self |= flags;
+        }
+    }
+}
+
+/// Key combination of a binding
+///
+/// [`SymKey::default`] has an empty key, which is not valid sway syntax, set
+/// one using [`SymKey::set_key`] before using it.
+#[derive(Display, Default, PartialEq, Eq, Hash)]
+#[display(fmt = "{group}{}{key}", "modifiers.to_prefix_string()")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SymKey {
     group: Group,
     modifiers: Modifiers,
@@ -441,6 +1136,7 @@ pub struct SymKey {
 }
 
 impl SymKey {
+    /// Key without group and modifiers
     pub fn key(key: impl Into<String>) -> Self {
         Self {
             group: Default::default(),
@@ -448,16 +1144,49 @@ impl SymKey {
             key: key.into(),
         }
     }
+
+    /// Sets the key
+    ///
+    /// ```
+    /// # use sway_command::commands::*;
+    /// let key = SymKey::default()
+    ///     .set_group(Group::Group2)
+    ///     .set_modifiers(Modifiers {
+    ///         mod4: true,
+    ///         ..Default::default()
+    ///     })
+    ///     .set_key("Return");
+    /// assert_eq!(key.to_string(), "Group2+Mod4+Return");
+    /// ```
+    pub fn set_key(mut self, key: impl Into<String>) -> Self {
+        self.key = key.into();
+        self
+    }
+
+    /// Sets the group
+    pub fn set_group(mut self, group: Group) -> Self {
+        self.group = group;
+        self
+    }
+
+    /// Sets the modifiers
+    pub fn set_modifiers(mut self, modifiers: Modifiers) -> Self {
+        self.modifiers = modifiers;
+        self
+    }
 }
 
-#[derive(Display)]
-#[display(fmt = "{modifiers}{key}")]
+#[derive(Display, PartialEq, Eq, Hash)]
+#[display(fmt = "{}{key}", "modifiers.to_prefix_string()")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SymCode {
     modifiers: Modifiers,
     key: u32,
 }
 
-#[derive(Display, Default)]
+#[derive(Display, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Group {
     #[default]
     #[display(fmt = "")]
@@ -472,16 +1201,11 @@ pub enum Group {
     Group4,
 }
 
-#[derive(Display, Default)]
-#[display(
-    fmt = "{}{}{}{}{}{}",
-    "when(*mod1, \"Mod1+\")",
-    "when(*mod2, \"Mod2+\")",
-    "when(*mod3, \"Mod3+\")",
-    "when(*mod4, \"Mod4+\")",
-    "when(*shift, \"Shift+\")",
-    "when(*control, \"Control+\")"
-)]
+/// Modifier keys of a key binding
+///
+/// Displays as the active modifiers joined with `+`, e.g. `Mod4+Shift`.
+#[derive(Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Modifiers {
     pub mod1: bool,
     pub mod2: bool,
@@ -491,13 +1215,69 @@ pub struct Modifiers {
     pub control: bool,
 }
 
-#[derive(Display)]
-#[display(
-    fmt = "{} {} {}",
-    "when(*locked, \"--locked\")",
-    "when(*no_warn, \"--no-warn\")",
-    "when(*reload, \"--reload\")"
-)]
+impl Modifiers {
+    /// The active modifiers each followed by a `+`, as used in front of a key
+    ///
+    /// ```
+    /// # use sway_command::commands::Modifiers;
+    /// let modifiers = Modifiers {
+    ///     mod4: true,
+    ///     shift: true,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(modifiers.to_string(), "Mod4+Shift");
+    /// assert_eq!(modifiers.to_prefix_string(), "Mod4+Shift+");
+    /// assert_eq!(Modifiers::default().to_prefix_string(), "");
+    /// ```
+    pub fn to_prefix_string(&self) -> String {
+        self.active_names().map(|name| format!("{name}+")).collect()
+    }
+
+    /// The names of the active modifiers, in the order they are displayed
+    ///
+    /// ```
+    /// # use sway_command::commands::Modifiers;
+    /// let modifiers = Modifiers {
+    ///     control: true,
+    ///     mod1: true,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(modifiers.active_names().collect::<Vec<_>>(), ["Mod1", "Control"]);
+    /// ```
+    pub fn active_names(&self) -> impl Iterator<Item = &'static str> {
+        [
+            self.mod1.then_some("Mod1"),
+            self.mod2.then_some("Mod2"),
+            self.mod3.then_some("Mod3"),
+            self.mod4.then_some("Mod4"),
+            self.shift.then_some("Shift"),
+            self.control.then_some("Control"),
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+impl fmt::Display for Modifiers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", separated(self.active_names(), '+'))
+    }
+}
+
+/// Flags of [`CriterialessCommand::Bindswitch`]
+///
+/// Displays only the set flags separated by a space:
+/// ```
+/// # use sway_command::commands::*;
+/// let flags = BindswitchFlags {
+///     locked: true,
+///     reload: true,
+///     ..Default::default()
+/// };
+/// assert_eq!(flags.to_string(), "--locked --reload");
+/// ```
+#[derive(Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BindswitchFlags {
     /// Run command when a screen locking program is active
     pub locked: bool,
@@ -512,7 +1292,31 @@ pub struct BindswitchFlags {
     pub reload: bool,
 }
 
-#[derive(Display)]
+impl BindswitchFlags {
+    /// Returns `true` if no flag is set
+    pub fn is_empty(&self) -> bool {
+        self.active().iter().all(Option::is_none)
+    }
+
+    fn active(&self) -> [Option<&'static str>; 3] {
+        [
+            self.locked.then_some("--locked"),
+            self.no_warn.then_some("--no-warn"),
+            self.reload.then_some("--reload"),
+        ]
+    }
+}
+
+impl fmt::Display for BindswitchFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", separated(self.active().into_iter().flatten(), ' '))
+    }
+}
+
+/// Switch of [`CriterialessCommand::Bindswitch`]
+#[derive(Display, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Switch {
     /// Laptop lid
     #[display(fmt = "lid")]
@@ -522,7 +1326,10 @@ pub enum Switch {
     Tablet,
 }
 
-#[derive(Display)]
+/// State of a [`Switch`], displayed after the switch separated by `:`
+#[derive(Display, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum SwitchState {
     #[display(fmt = "on")]
     On,
@@ -534,10 +1341,11 @@ pub enum SwitchState {
 
 #[derive(Display)]
 #[display(
-    fmt = "{class} {border} {background} {text} {} {}",
-    "to_string_or_empty(indicator)",
-    "to_string_or_empty(&indicator.and(*child_border))"
+    fmt = "{class} {border} {background} {text}{}{}",
+    "then_or_empty(indicator, |indicator| format!(\" {indicator}\"))",
+    "then_or_empty(&indicator.and(*child_border), |child_border| format!(\" {child_border}\"))"
 )]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ClientClass {
     pub class: Class,
     /// The border around the title bar
@@ -557,11 +1365,64 @@ pub struct ClientClass {
     pub child_border: Option<Color>,
 }
 
-#[derive(Display, Clone, Copy)]
+/// Builder for a [`CriterialessCommand::Client`] command, created by
+/// [`CriterialessCommand::client_class`]
+pub struct ClientClassBuilder(ClientClass);
+
+impl ClientClassBuilder {
+    /// Sets [`ClientClass::indicator`]
+    pub fn indicator(mut self, color: Color) -> Self {
+        self.0.indicator = Some(color);
+        self
+    }
+
+    /// Sets [`ClientClass::child_border`] together with the
+    /// [`ClientClass::indicator`] it requires
+    ///
+    /// ```
+    /// # use sway_command::commands::*;
+    /// let white = Color::rgb(0xFF, 0xFF, 0xFF);
+    /// let urgent = CriterialessCommand::client_class(Class::Urgent, white, white, white)
+    ///     .child_border(Color::rgb(0x2E, 0x9E, 0xF4), Color::rgb(0x90, 0x00, 0x00))
+    ///     .into_command();
+    /// assert_eq!(
+    ///     urgent.to_string(),
+    ///     "client.urgent #FFFFFF #FFFFFF #FFFFFF #2E9EF4 #900000"
+    /// );
+    /// ```
+    pub fn child_border(mut self, indicator: Color, child_border: Color) -> Self {
+        self.0.indicator = Some(indicator);
+        self.0.child_border = Some(child_border);
+        self
+    }
+
+    /// Creates the [`CriterialessCommand::Client`] command
+    pub fn into_command(self) -> CriterialessCommand {
+        CriterialessCommand::Client(self.0)
+    }
+}
+
+impl From<ClientClassBuilder> for CriterialessCommand {
+    fn from(builder: ClientClassBuilder) -> Self {
+        builder.into_command()
+    }
+}
+
+/// An RGB color with optional alpha channel
+///
+/// Equality and hashing compare all four fields, an explicit alpha is therefore
+/// never equal to an omitted one, even if it is fully opaque, as both result in
+/// a different string representation:
+/// ```
+/// # use sway_command::commands::Color;
+/// assert!(Color::rgb(0, 0, 0) != Color::rgba(0, 0, 0, 255));
+/// ```
+#[derive(Display, Clone, Copy, PartialEq, Eq, Hash)]
 #[display(
-    fmt = "#{red:X}{green:X}{blue:X}{}",
-    "then_or_empty(alpha, |a| format!(\"{a:X}\"))"
+    fmt = "#{red:02X}{green:02X}{blue:02X}{}",
+    "then_or_empty(alpha, |a| format!(\"{a:02X}\"))"
 )]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Color {
     red: u8,
     green: u8,
@@ -569,7 +1430,31 @@ pub struct Color {
     alpha: Option<u8>,
 }
 
+impl Color {
+    /// Creates an opaque color without alpha channel
+    pub fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self {
+            red,
+            green,
+            blue,
+            alpha: None,
+        }
+    }
+
+    /// Creates a color with alpha channel
+    pub fn rgba(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
+        Self {
+            red,
+            green,
+            blue,
+            alpha: Some(alpha),
+        }
+    }
+}
+
 #[derive(Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Class {
     /// The window that has focus
     #[display(fmt = "focused")]
@@ -599,18 +1484,22 @@ pub enum Class {
 }
 
 #[derive(Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum DefaultBorder {
     #[display(fmt = "none")]
     None,
     /// A border of thickness n and a title bar
-    #[display(fmt = "normal {}", "to_string_or_empty(_0)")]
+    #[display(fmt = "normal{}", "then_or_empty(_0, |px| format!(\" {px}\"))")]
     Normal(Option<u32>),
     /// A border without title bar n pixels thick
-    #[display(fmt = "pixel {}", "to_string_or_empty(_0)")]
+    #[display(fmt = "pixel{}", "then_or_empty(_0, |px| format!(\" {px}\"))")]
     Pixel(Option<u32>),
 }
 
 #[derive(Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum FloatingModifierMode {
     /// Left click is used for moving and right click for resizing
     #[display(fmt = "normal")]
@@ -621,6 +1510,8 @@ pub enum FloatingModifierMode {
 }
 
 #[derive(Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum MouseFocus {
     /// Moving your mouse over a window will focus that window
     #[display(fmt = "yes")]
@@ -634,18 +1525,27 @@ pub enum MouseFocus {
 }
 
 #[derive(Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum WindowActivationFocus {
     /// The window will become focused only if it is already visible, otherwise
     /// the urgent state will be set
+    #[display(fmt = "smart")]
     Smart,
     /// The urgent state will be set for that window
+    #[display(fmt = "urgent")]
     Urgent,
     /// The window will become focused
+    #[display(fmt = "focus")]
     Focus,
+    /// The window will neither be focused nor marked urgent
+    #[display(fmt = "none")]
     None,
 }
 
 #[derive(Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum FocusWrapping {
     /// Focus will be wrapped to the opposite edge of the container, if there
     /// are no other containers in the direction
@@ -666,6 +1566,8 @@ pub enum FocusWrapping {
 }
 
 #[derive(Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum EdgeBorders {
     #[display(fmt = "none")]
     None,
@@ -682,6 +1584,8 @@ pub enum EdgeBorders {
 }
 
 #[derive(Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum SmartBorders {
     /// Borders will only be enabled if the workspace has more than one visible
     /// child
@@ -696,6 +1600,8 @@ pub enum SmartBorders {
 }
 
 #[derive(Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum SmartGaps {
     /// Gaps will only be enabled if a workspace has more than one child
     #[display(fmt = "on")]
@@ -709,21 +1615,39 @@ pub enum SmartGaps {
     InverseOuter,
 }
 
-#[derive(Display)]
+/// Flags of [`CriterialessCommand::Mark`]
+///
+/// [`MarkModification::default`] is [`MarkModification::Replace`], the
+/// behavior of `mark` without flags.
+///
+/// ```
+/// # use sway_command::commands::*;
+/// assert!(matches!(MarkModification::default(), MarkModification::Replace));
+/// ```
+#[derive(Display, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum MarkModification {
     /// Will add identifier to the list of current marks
+    #[display(fmt = "--add")]
     Add,
     /// Will add identifier to the list of current marks, will remove mark if it
     /// is already marked
+    #[display(fmt = "--add --toggle")]
     AddToggle,
     /// Sets identifier as the only mark on a window
+    #[default]
+    #[display(fmt = "--replace")]
     Replace,
     /// Sets identifier as the only mark on a window, will remove mark if it
     /// is already marked
+    #[display(fmt = "--replace --toggle")]
     ReplaceToggle,
 }
 
 #[derive(Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum MouseWarping {
     /// The mouse will be moved to new outputs as you move focus between them
     #[display(fmt = "output")]
@@ -736,26 +1660,38 @@ pub enum MouseWarping {
 }
 
 #[derive(Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum PopupDuringFullscreen {
     /// the dialog will be displayed
+    #[display(fmt = "smart")]
     Smart,
     /// the dialog will not be rendered
+    #[display(fmt = "ignore")]
     Ignore,
     /// the view will exit fullscreen mode and the dialog will be rendered
+    #[display(fmt = "leave_fullscreen")]
     LeaveFullscreen,
 }
 
 #[derive(Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum OpacityModification {
+    /// Sets the opacity to the value
     #[display(fmt = "set")]
     Set,
+    /// Increases the opacity by the value
     #[display(fmt = "plus")]
     Plus,
+    /// Decreases the opacity by the value
     #[display(fmt = "minus")]
     Minus,
 }
 
 #[derive(Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum TitleAlign {
     #[display(fmt = "left")]
     Left,
@@ -766,6 +1702,8 @@ pub enum TitleAlign {
 }
 
 #[derive(Display)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Urgent {
     #[display(fmt = "enable")]
     Enable,
@@ -776,3 +1714,328 @@ pub enum Urgent {
     #[display(fmt = "deny")]
     Deny,
 }
+
+#[test]
+fn bindswitch_flags() {
+    assert_eq!(BindswitchFlags::default().to_string(), "");
+    let cmd = CriterialessCommand::Bindswitch(
+        Default::default(),
+        Switch::Lid,
+        SwitchState::On,
+        "exec swaylock".into(),
+    );
+    assert_eq!(cmd.to_string(), "bindswitch lid:on exec swaylock");
+    let cmd = CriterialessCommand::Bindswitch(
+        BindswitchFlags {
+            no_warn: true,
+            ..Default::default()
+        },
+        Switch::Tablet,
+        SwitchState::Toggle,
+        "reload".into(),
+    );
+    assert_eq!(cmd.to_string(), "bindswitch --no-warn tablet:toggle reload");
+}
+
+#[test]
+fn empty_modifiers() {
+    assert_eq!(Modifiers::default().to_string(), "");
+    assert_eq!(Modifiers::default().active_names().count(), 0);
+    assert_eq!(SymKey::key("Return").to_string(), "Return");
+    let key = SymKey::key("Return").set_modifiers(Modifiers {
+        mod4: true,
+        ..Default::default()
+    });
+    assert_eq!(key.to_string(), "Mod4+Return");
+}
+
+#[test]
+fn bindswitch_switches() {
+    for (switch, name) in [(Switch::Lid, "lid"), (Switch::Tablet, "tablet")] {
+        for (state, state_name) in [
+            (SwitchState::On, "on"),
+            (SwitchState::Off, "off"),
+            (SwitchState::Toggle, "toggle"),
+        ] {
+            let cmd =
+                CriterialessCommand::Bindswitch(Default::default(), switch, state, "reload".into());
+            assert_eq!(
+                cmd.to_string(),
+                format!("bindswitch {name}:{state_name} reload")
+            );
+        }
+    }
+    assert_eq!(
+        CriterialessCommand::bindswitch_lid_close("reload").to_string(),
+        "bindswitch lid:on reload"
+    );
+    assert_eq!(
+        CriterialessCommand::bindswitch_tablet_mode("reload").to_string(),
+        "bindswitch tablet:on reload"
+    );
+}
+
+#[test]
+fn output_subcommands() {
+    let output = |subcommands| CriterialessCommand::Output("eDP-1".into(), subcommands);
+    assert_eq!(
+        output(vec![OutputSubcommand::Disable]).to_string(),
+        "output eDP-1 disable"
+    );
+    assert_eq!(
+        output(vec![OutputSubcommand::Enable]).to_string(),
+        "output eDP-1 enable"
+    );
+    assert_eq!(
+        output(vec!["scale 2".into(), OutputSubcommand::Enable]).to_string(),
+        "output eDP-1 scale 2 enable"
+    );
+}
+
+#[test]
+fn mark_modification() {
+    for (modification, flags) in [
+        (MarkModification::Add, "--add"),
+        (MarkModification::AddToggle, "--add --toggle"),
+        (MarkModification::Replace, "--replace"),
+        (MarkModification::ReplaceToggle, "--replace --toggle"),
+    ] {
+        assert_eq!(modification.to_string(), flags);
+    }
+}
+
+#[test]
+fn mode() {
+    assert_eq!(
+        CriterialessCommand::Mode("default".into()).to_string(),
+        "mode default"
+    );
+}
+
+#[test]
+fn color_display() {
+    assert_eq!(Color::rgb(0x01, 0x0A, 0xFF).to_string(), "#010AFF");
+    assert_eq!(Color::rgba(0xA0, 0, 0x0B, 0x01).to_string(), "#A0000B01");
+}
+
+#[test]
+fn color_hash() {
+    use std::collections::HashSet;
+
+    let colors: HashSet<_> = [
+        Color::rgb(0xAA, 0xBB, 0xCC),
+        Color::rgb(0xAA, 0xBB, 0xCC),
+        Color::rgba(0xAA, 0xBB, 0xCC, 0xFF),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(colors.len(), 2);
+    assert!(colors.contains(&Color::rgb(0xAA, 0xBB, 0xCC)));
+    assert!(colors.contains(&Color::rgba(0xAA, 0xBB, 0xCC, 0xFF)));
+}
+
+#[test]
+fn sym_key_hash() {
+    use std::collections::HashSet;
+
+    let mod4 = || Modifiers {
+        mod4: true,
+        ..Default::default()
+    };
+    let keys: HashSet<_> = [
+        SymKey::key("Return").set_modifiers(mod4()),
+        SymKey::key("Return").set_modifiers(mod4()),
+        SymKey::key("Return"),
+        SymKey::key("Return")
+            .set_modifiers(mod4())
+            .set_group(Group::Group2),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(keys.len(), 3);
+    assert!(keys.contains(&SymKey::key("Return").set_modifiers(mod4())));
+
+    let flags = BindFlags {
+        release: true,
+        ..Default::default()
+    };
+    let bindings: HashSet<_> = [
+        (BindFlags::default(), SymKey::key("q")),
+        (BindFlags::default(), SymKey::key("q")),
+        (flags, SymKey::key("q")),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(bindings.len(), 2);
+}
+
+#[test]
+fn client_class_builder() {
+    let white = Color::rgb(0xFF, 0xFF, 0xFF);
+    let command: CriterialessCommand =
+        CriterialessCommand::client_class(Class::Unfocused, white, white, white).into();
+    assert_eq!(
+        command.to_string(),
+        "client.unfocused #FFFFFF #FFFFFF #FFFFFF"
+    );
+    let command = CriterialessCommand::client_class(Class::Urgent, white, white, white)
+        .child_border(white, Color::rgba(0x10, 0x20, 0x30, 0x40))
+        .into_command();
+    assert_eq!(
+        command.to_string(),
+        "client.urgent #FFFFFF #FFFFFF #FFFFFF #FFFFFF #10203040"
+    );
+}
+
+#[test]
+fn bind_flags_union() {
+    let device = |name: &str| BindFlags {
+        input_device: Some(name.into()),
+        ..Default::default()
+    };
+    let mut flags = BindFlags {
+        release: true,
+        ..Default::default()
+    } | device("keyboard");
+    assert!(flags.release);
+    assert_eq!(flags.input_device.as_deref(), Some("keyboard"));
+    flags |= device("touchpad");
+    assert_eq!(flags.input_device.as_deref(), Some("keyboard"));
+
+    flags.extend([
+        BindFlags {
+            locked: true,
+            ..Default::default()
+        },
+        BindFlags {
+            no_warn: true,
+            ..Default::default()
+        },
+    ]);
+    assert!(
+        flags
+            == BindFlags {
+                release: true,
+                locked: true,
+                no_warn: true,
+                ..device("keyboard")
+            }
+    );
+}
+
+#[test]
+fn bind_flags_display() {
+    assert_eq!(
+        CriterialessCommand::Bindsym(
+            BindFlags::default(),
+            SymKey::key("Return"),
+            "exec foot".into()
+        )
+        .to_string(),
+        "bindsym Return exec foot"
+    );
+    let flags = BindFlags {
+        release: true,
+        locked: true,
+        ..Default::default()
+    };
+    assert_eq!(flags.to_string(), "--release --locked");
+    assert_eq!(
+        CriterialessCommand::Unbindcode(
+            flags,
+            SymCode {
+                modifiers: Default::default(),
+                key: 36,
+            },
+        )
+        .to_string(),
+        "unbindcode --release --locked 36"
+    );
+}
+
+#[test]
+fn opacity() {
+    assert_eq!(
+        CriterialessCommand::Opacity(OpacityModification::Set, 0.8).to_string(),
+        "opacity set 0.8"
+    );
+    assert_eq!(
+        CriterialessCommand::Opacity(OpacityModification::Plus, 0.1).to_string(),
+        "opacity plus 0.1"
+    );
+    assert_eq!(
+        CriterialessCommand::Opacity(OpacityModification::Minus, 1.0).to_string(),
+        "opacity minus 1"
+    );
+}
+
+#[test]
+fn criterialess_command_from_str() {
+    for command in [
+        r#"assign [app_id="foot"] → workspace number 2"#,
+        "assign [class=\"Firefox\"] → output DP-1",
+        "exec foot --server",
+        "exec_always swaybg -i bg.png",
+        "floating_maximum_size 800 x 600",
+        "focus_follows_mouse always",
+        "focus_on_window_activation urgent",
+        "focus_wrapping workspace",
+        r#"for_window [app_id="mpv" floating] inhibit_idle fullscreen"#,
+        "force_display_urgency_hint 500 ms",
+        "gaps inner 10",
+        "kill",
+        "mark --add --toggle web",
+        "mode resize",
+        "mouse_warping container",
+        "no_focus [title=\"^Peek\"]",
+        "popup_during_fullscreen leave_fullscreen",
+        "set $mod Mod4",
+        "show_marks yes",
+        "smart_borders no_gaps",
+        "smart_gaps inverse_outer",
+        "tiling_drag toggle",
+        "tiling_drag_threshold 9",
+        "title_align center",
+        "titlebar_border_thickness 2",
+        "titlebar_padding 4",
+        "titlebar_padding 4 2",
+        "unmark web",
+        "urgent allow",
+        "workspace back_and_forth",
+        "workspace 3:mail gaps outer 0",
+        "workspace_auto_back_and_forth no",
+    ] {
+        assert_eq!(
+            command.parse::<CriterialessCommand>().unwrap().to_string(),
+            command
+        );
+    }
+    for (command, expected) in [
+        (
+            "assign [app_id=\"foot\"] 2",
+            "assign [app_id=\"foot\"] → workspace 2",
+        ),
+        ("mark web", "mark --replace web"),
+        (
+            "force_display_urgency_hint 500",
+            "force_display_urgency_hint 500 ms",
+        ),
+    ] {
+        assert_eq!(
+            command.parse::<CriterialessCommand>().unwrap().to_string(),
+            expected
+        );
+    }
+    for command in [
+        "",
+        "exec",
+        "set mod Mod4",
+        "workspace 1 output DP-1",
+        "workspace --no-auto-back-and-forth 1",
+        "titlebar_padding 0",
+        "bindsym a exec foot",
+        "floating enable",
+    ] {
+        assert!(command.parse::<CriterialessCommand>().is_err(), "{command}");
+    }
+}
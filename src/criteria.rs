@@ -1,12 +1,33 @@
-use derive_more::{AsRef, Display};
+use std::{cmp::Ordering, fmt, ops::BitOr, slice, str::FromStr, sync::OnceLock};
+
+use derive_more::{Deref, Display};
 #[cfg(feature = "serde")]
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use vec1::Vec1;
+
+use crate::error::CriteriaParseError;
 
-#[derive(AsRef, Display, Clone)]
-#[display(fmt = "{rep}")]
+/// A non-empty list of [`Criteria`] that all need to match
+///
+/// Derefs to the contained criteria:
+/// ```
+/// # use sway_command::criteria::*;
+/// let mut list = CriteriaList::floating();
+/// list.criteria(Criteria::focused_app_id());
+/// assert_eq!(list.len(), 2);
+/// assert_eq!(list[0].to_string(), "floating");
+/// ```
+#[derive(Deref, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(from = "Vec1<Criteria>", into = "Vec1<Criteria>")
+)]
 pub struct CriteriaList {
-    #[as_ref(forward)]
-    rep: String,
+    // Cleared when the criteria are accessed mutably and rebuild on the next
+    // access
+    rep: OnceLock<String>,
+    #[deref(forward)]
     criteria: Vec<Criteria>,
 }
 
@@ -15,21 +36,306 @@ impl CriteriaList {
         &self.criteria
     }
     pub fn criteria(&mut self, criteria: Criteria) -> &mut Self {
-        assert_eq!(self.rep.pop(), Some(']'));
-        self.rep.push_str(" {criteria}]");
+        if let Some(rep) = self.rep.get_mut() {
+            assert_eq!(rep.pop(), Some(']'));
+            rep.push_str(&format!(" {criteria}]"));
+        }
         self.criteria.push(criteria);
         self
     }
 
     pub fn new(criteria: Criteria) -> CriteriaList {
         Self {
-            rep: format!("[{criteria}]"),
+            rep: OnceLock::new(),
             criteria: vec![criteria],
         }
     }
+
+    /// Returns the list with the criteria sorted, see [`Criteria`]'s `Ord`
+    /// implementation
+    ///
+    /// As the order of criteria doesn't affect matching, this allows comparing
+    /// lists independent of the order the criteria were added in:
+    /// ```
+    /// # use sway_command::criteria::*;
+    /// let a = CriteriaList::tiling() | CriteriaList::app_id("foot");
+    /// let b = CriteriaList::app_id("foot") | CriteriaList::tiling();
+    /// assert!(a != b);
+    /// assert!(a.canonical() == b.canonical());
+    /// assert_eq!(a.canonical().to_string(), r#"[app_id="foot" tiling]"#);
+    /// ```
+    pub fn canonical(&self) -> CriteriaList {
+        let mut criteria = self.criteria.clone();
+        criteria.sort();
+        Self {
+            rep: OnceLock::new(),
+            criteria,
+        }
+    }
+
+    fn rep(&self) -> &str {
+        self.rep.get_or_init(|| {
+            format!(
+                "[{}]",
+                self.criteria
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            )
+        })
+    }
+
+    /// Matches windows with the app id, see [`Criteria::AppId`]
+    ///
+    /// ```
+    /// # use sway_command::criteria::CriteriaList;
+    /// assert_eq!(CriteriaList::app_id("firefox").to_string(), r#"[app_id="firefox"]"#);
+    /// ```
+    pub fn app_id(id: impl Into<String>) -> CriteriaList {
+        Self::new(Criteria::AppId(OrFocused::Value(id.into())))
+    }
+
+    /// Matches windows with a title matching `regex`, see [`Criteria::Title`]
+    pub fn title(regex: impl Into<String>) -> CriteriaList {
+        Self::new(Criteria::Title(OrFocused::Value(regex.into())))
+    }
+
+    /// Matches windows with a class matching `regex`, see [`Criteria::Class`]
+    pub fn class(regex: impl Into<String>) -> CriteriaList {
+        Self::new(Criteria::Class(OrFocused::Value(regex.into())))
+    }
+
+    /// Matches floating windows, see [`Criteria::Floating`]
+    pub fn floating() -> CriteriaList {
+        Self::new(Criteria::Floating)
+    }
+
+    /// Matches tiling windows, see [`Criteria::Tiling`]
+    pub fn tiling() -> CriteriaList {
+        Self::new(Criteria::Tiling)
+    }
+
+    /// Matches windows on the workspace, see [`Criteria::Workspace`]
+    pub fn workspace(name: impl Into<String>) -> CriteriaList {
+        Self::new(Criteria::Workspace(OrFocused::Value(name.into())))
+    }
+
+    /// Matches windows with the process ID, see [`Criteria::Pid`]
+    pub fn pid(pid: u32) -> CriteriaList {
+        Self::new(Criteria::Pid(pid))
+    }
+
+    /// Matches the container with the ID, see [`Criteria::ConId`]
+    pub fn con_id(id: u32) -> CriteriaList {
+        Self::new(Criteria::ConId(OrFocused::Value(id)))
+    }
+}
+
+impl From<Vec1<Criteria>> for CriteriaList {
+    fn from(criteria: Vec1<Criteria>) -> Self {
+        Self {
+            rep: OnceLock::new(),
+            criteria: criteria.into_vec(),
+        }
+    }
+}
+
+impl fmt::Display for CriteriaList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.rep())
+    }
 }
 
-#[derive(Display, Clone)]
+/// Shows both the string representation and the criteria
+///
+/// ```
+/// # use sway_command::criteria::*;
+/// assert_eq!(
+///     format!("{:?}", CriteriaList::app_id("firefox")),
+///     r#"CriteriaList { rep: "[app_id=\"firefox\"]", criteria: [AppId(Value("firefox"))] }"#
+/// );
+/// ```
+impl fmt::Debug for CriteriaList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CriteriaList")
+            .field("rep", &self.rep())
+            .field("criteria", &self.criteria)
+            .finish()
+    }
+}
+
+impl AsRef<str> for CriteriaList {
+    fn as_ref(&self) -> &str {
+        self.rep()
+    }
+}
+
+impl<'a> IntoIterator for &'a CriteriaList {
+    type IntoIter = slice::Iter<'a, Criteria>;
+    type Item = &'a Criteria;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.criteria.iter()
+    }
+}
+
+/// Allows modifying the criteria in place
+///
+/// ```
+/// # use sway_command::criteria::*;
+/// let mut list = CriteriaList::app_id("firefox");
+/// for criteria in &mut list {
+///     *criteria = Criteria::focused_app_id();
+/// }
+/// assert_eq!(list.to_string(), r#"[app_id="__focused__"]"#);
+/// ```
+impl<'a> IntoIterator for &'a mut CriteriaList {
+    type IntoIter = slice::IterMut<'a, Criteria>;
+    type Item = &'a mut Criteria;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.rep.take();
+        self.criteria.iter_mut()
+    }
+}
+
+/// Adds the criteria, all criteria need to match
+///
+/// ```
+/// # use sway_command::criteria::*;
+/// let list = CriteriaList::floating() | Criteria::focused_app_id();
+/// assert_eq!(list.to_string(), r#"[floating app_id="__focused__"]"#);
+/// ```
+impl BitOr<Criteria> for CriteriaList {
+    type Output = CriteriaList;
+
+    fn bitor(mut self, criteria: Criteria) -> Self::Output {
+        self.rep.take();
+        self.criteria.push(criteria);
+        self
+    }
+}
+
+/// Merges both lists, all criteria need to match
+///
+/// ```
+/// # use sway_command::criteria::*;
+/// let list = CriteriaList::floating() | CriteriaList::app_id("firefox");
+/// assert_eq!(list.to_string(), r#"[floating app_id="firefox"]"#);
+/// ```
+impl BitOr<CriteriaList> for CriteriaList {
+    type Output = CriteriaList;
+
+    fn bitor(mut self, other: CriteriaList) -> Self::Output {
+        self.rep.take();
+        self.criteria.extend(other.criteria);
+        self
+    }
+}
+
+/// Parses criteria in the syntax used by sway, values can be quoted
+///
+/// ```
+/// # use sway_command::criteria::*;
+/// let list: CriteriaList = r#"[app_id="firefox" floating title=__focused__]"#.parse()?;
+/// assert!(
+///     list.get_criteria()
+///         == [
+///             Criteria::AppId(OrFocused::Value("firefox".into())),
+///             Criteria::Floating,
+///             Criteria::Title(OrFocused::Focused),
+///         ]
+/// );
+/// assert!("floating".parse::<CriteriaList>().is_err());
+/// # Ok::<(), sway_command::error::CriteriaParseError>(())
+/// ```
+impl FromStr for CriteriaList {
+    type Err = CriteriaParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let inner = s
+            .trim()
+            .strip_prefix('[')
+            .and_then(|s| s.strip_suffix(']'))
+            .ok_or(CriteriaParseError::MissingBrackets)?;
+
+        let mut criteria = Vec::new();
+        let mut start = None;
+        let mut quoted = false;
+        let mut escaped = false;
+        for (idx, c) in inner.char_indices() {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => quoted = !quoted,
+                c if c.is_whitespace() && !quoted => {
+                    if let Some(start) = start.take() {
+                        criteria.push(inner[start..idx].parse()?);
+                    }
+                    continue;
+                }
+                _ => {}
+            }
+            start.get_or_insert(idx);
+        }
+        if quoted {
+            return Err(CriteriaParseError::UnclosedQuote);
+        }
+        if let Some(start) = start {
+            criteria.push(inner[start..].parse()?);
+        }
+
+        Vec1::try_from_vec(criteria)
+            .map(Self::from)
+            .map_err(|_| CriteriaParseError::Empty)
+    }
+}
+
+/// Splits a leading criteria list, including its brackets, from the rest of
+/// `s`, ignoring `]` inside of quoted values and escaped characters
+pub(crate) fn split_criteria(s: &str) -> Option<(&str, &str)> {
+    let s = s.trim_start();
+    if !s.starts_with('[') {
+        return None;
+    }
+    let mut quoted = false;
+    let mut escaped = false;
+    for (idx, c) in s.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => quoted = !quoted,
+            ']' if !quoted => return Some((&s[..=idx], s[idx + 1..].trim())),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Compares the string representation
+impl PartialEq for CriteriaList {
+    fn eq(&self, other: &Self) -> bool {
+        self.rep() == other.rep()
+    }
+}
+
+impl Eq for CriteriaList {}
+
+impl From<CriteriaList> for Vec1<Criteria> {
+    fn from(list: CriteriaList) -> Self {
+        Vec1::try_from_vec(list.criteria).expect("CriteriaList is never empty")
+    }
+}
+
+/// A single criteria to match windows by
+///
+/// Criteria are compared and ordered by their string representation, i.e. by
+/// their name first and then by their value as a string, so `pid="10"` is
+/// ordered before `pid="9"`.
+#[derive(Display, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Criteria {
     /// Compare value against the app id. Can be a regular expression. If value
     /// is __focused__, then the app id must be the same as that of the
@@ -68,7 +374,7 @@ pub enum Criteria {
     Instance(OrFocused<String>),
 
     /// Compare value against the window's process ID. Must be numeric.
-    #[display(fmt = "Pid=\"{}\"", "_0")]
+    #[display(fmt = "pid=\"{}\"", "_0")]
     Pid(u32),
 
     /// Compare value against the window shell, such as "xdg_shell" or
@@ -89,7 +395,9 @@ pub enum Criteria {
 
     /// Compares the urgent state of the window. Can be "first", "last",
     /// "latest", "newest", "oldest" or "recent".
-    // TODO make enum
+    ///
+    /// Like all criteria values the value is quoted, sway accepts both
+    /// `urgent=latest` and `urgent="latest"`.
     #[display(fmt = "urgent=\"{}\"", "_0")]
     Urgent(Urgent),
 
@@ -113,7 +421,144 @@ pub enum Criteria {
     Workspace(OrFocused<String>),
 }
 
-#[derive(Display, Debug, Clone)]
+/// Parses a single criteria, e.g. `app_id="firefox"` or `floating`
+impl FromStr for Criteria {
+    type Err = CriteriaParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((name, value)) = s.split_once('=') else {
+            return match s {
+                "floating" => Ok(Criteria::Floating),
+                "tiling" => Ok(Criteria::Tiling),
+                _ => Err(CriteriaParseError::UnknownCriteria { name: s.to_owned() }),
+            };
+        };
+        let value = value
+            .strip_prefix('"')
+            .and_then(|value| value.strip_suffix('"'))
+            .unwrap_or(value);
+        let invalid = || CriteriaParseError::InvalidValue {
+            name: name.to_owned(),
+            value: value.to_owned(),
+        };
+        let string = || match value {
+            "__focused__" => OrFocused::Focused,
+            value => OrFocused::Value(value.to_owned()),
+        };
+        let number = || value.parse::<u32>().map_err(|_| invalid());
+        Ok(match name {
+            "app_id" => Criteria::AppId(string()),
+            "class" => Criteria::Class(string()),
+            "con_id" => Criteria::ConId(match value {
+                "__focused__" => OrFocused::Focused,
+                _ => OrFocused::Value(number()?),
+            }),
+            "con_mark" => Criteria::ConMark(value.to_owned()),
+            "id" => Criteria::Id(number()?),
+            "instance" => Criteria::Instance(string()),
+            "pid" => Criteria::Pid(number()?),
+            "shell" => Criteria::Shell(string()),
+            "title" => Criteria::Title(string()),
+            "urgent" => Criteria::Urgent(match value {
+                "first" => Urgent::First,
+                "last" => Urgent::Last,
+                "latest" => Urgent::Latest,
+                "newest" => Urgent::Newest,
+                "oldest" => Urgent::Oldest,
+                "recent" => Urgent::Recent,
+                _ => return Err(invalid()),
+            }),
+            "window_role" => Criteria::WindowRole(string()),
+            "window_type" => Criteria::WindowType(match value {
+                "normal" => WindowType::Normal,
+                "dialog" => WindowType::Dialog,
+                "utility" => WindowType::Utility,
+                "toolbar" => WindowType::Toolbar,
+                "splash" => WindowType::Splash,
+                "menu" => WindowType::Menu,
+                "dropdown_menu" => WindowType::DropdownMenu,
+                "popup_menu" => WindowType::PopupMenu,
+                "tooltip" => WindowType::Tooltip,
+                "notification" => WindowType::Notification,
+                _ => return Err(invalid()),
+            }),
+            "workspace" => Criteria::Workspace(string()),
+            _ => {
+                return Err(CriteriaParseError::UnknownCriteria {
+                    name: name.to_owned(),
+                })
+            }
+        })
+    }
+}
+
+impl Criteria {
+    /// Matches windows with the same app id as the currently focused window
+    ///
+    /// ```
+    /// # use sway_command::criteria::Criteria;
+    /// assert_eq!(Criteria::focused_app_id().to_string(), r#"app_id="__focused__""#);
+    /// ```
+    pub fn focused_app_id() -> Criteria {
+        Criteria::AppId(OrFocused::Focused)
+    }
+
+    /// Matches windows with the same title as the currently focused window
+    pub fn focused_title() -> Criteria {
+        Criteria::Title(OrFocused::Focused)
+    }
+
+    /// Matches windows with the same class as the currently focused window
+    pub fn focused_class() -> Criteria {
+        Criteria::Class(OrFocused::Focused)
+    }
+
+    /// Matches windows with the same instance as the currently focused window
+    pub fn focused_instance() -> Criteria {
+        Criteria::Instance(OrFocused::Focused)
+    }
+
+    /// Matches windows with the same shell as the currently focused window
+    pub fn focused_shell() -> Criteria {
+        Criteria::Shell(OrFocused::Focused)
+    }
+
+    /// Matches all windows on the currently focused workspace
+    pub fn focused_workspace() -> Criteria {
+        Criteria::Workspace(OrFocused::Focused)
+    }
+
+    /// Matches windows with the same window role as the currently focused
+    /// window
+    pub fn focused_window_role() -> Criteria {
+        Criteria::WindowRole(OrFocused::Focused)
+    }
+}
+
+/// Compares the string representation
+impl PartialEq for Criteria {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for Criteria {}
+
+impl PartialOrd for Criteria {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Criteria {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_string().cmp(&other.to_string())
+    }
+}
+
+#[derive(Display, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum OrFocused<T> {
     #[display(fmt = "__focused__")]
     Focused,
@@ -121,16 +566,60 @@ pub enum OrFocused<T> {
     Value(T),
 }
 
+impl<T> OrFocused<T> {
+    /// Maps the contained value, leaving [`OrFocused::Focused`] untouched
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> OrFocused<U> {
+        match self {
+            OrFocused::Focused => OrFocused::Focused,
+            OrFocused::Value(value) => OrFocused::Value(f(value)),
+        }
+    }
+
+    /// Converts from `&OrFocused<T>` to `OrFocused<&T>`
+    pub fn as_ref(&self) -> OrFocused<&T> {
+        match self {
+            OrFocused::Focused => OrFocused::Focused,
+            OrFocused::Value(value) => OrFocused::Value(value),
+        }
+    }
+
+    /// Returns the contained value or `None` for [`OrFocused::Focused`]
+    pub fn value(&self) -> Option<&T> {
+        match self {
+            OrFocused::Focused => None,
+            OrFocused::Value(value) => Some(value),
+        }
+    }
+
+    /// Returns `true` for [`OrFocused::Focused`]
+    pub fn is_focused(&self) -> bool {
+        matches!(self, OrFocused::Focused)
+    }
+
+    /// Returns the contained value or `default` for [`OrFocused::Focused`]
+    ///
+    /// ```
+    /// # use sway_command::criteria::OrFocused;
+    /// assert_eq!(OrFocused::Value(1).unwrap_or(2), 1);
+    /// assert_eq!(OrFocused::Focused.unwrap_or(2), 2);
+    /// ```
+    pub fn unwrap_or(self, default: T) -> T {
+        match self {
+            OrFocused::Focused => default,
+            OrFocused::Value(value) => value,
+        }
+    }
+}
+
 impl<T> From<T> for OrFocused<T> {
     fn from(t: T) -> Self {
         OrFocused::Value(t)
     }
 }
 
-#[derive(Display)]
-#[cfg_attr(feature = "serde", derive(Deserialize))]
-#[serde(rename_all = "snake_case")]
-#[derive(Clone)]
+#[derive(Display, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Urgent {
     #[display(fmt = "first")]
     First,
@@ -146,10 +635,9 @@ pub enum Urgent {
     Recent,
 }
 
-#[derive(Display)]
-#[cfg_attr(feature = "serde", derive(Deserialize))]
-#[serde(rename_all = "snake_case")]
-#[derive(Clone)]
+#[derive(Display, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum WindowType {
     #[display(fmt = "normal")]
     Normal,
@@ -177,3 +665,104 @@ pub enum WindowType {
 fn test() {
     assert_eq!("first", Urgent::First.to_string());
 }
+
+#[test]
+fn urgent_criteria() {
+    for (urgent, expected) in [
+        (Urgent::First, r#"urgent="first""#),
+        (Urgent::Last, r#"urgent="last""#),
+        (Urgent::Latest, r#"urgent="latest""#),
+        (Urgent::Newest, r#"urgent="newest""#),
+        (Urgent::Oldest, r#"urgent="oldest""#),
+        (Urgent::Recent, r#"urgent="recent""#),
+    ] {
+        assert_eq!(Criteria::Urgent(urgent).to_string(), expected);
+    }
+}
+
+#[test]
+fn criteria_eq() {
+    assert!(Criteria::focused_app_id() == Criteria::AppId(OrFocused::Focused));
+    assert!(Criteria::Pid(1) != Criteria::Pid(2));
+    assert!(CriteriaList::floating() == CriteriaList::new(Criteria::Floating));
+}
+
+#[test]
+fn criteria_ord() {
+    assert!(Criteria::Pid(10) < Criteria::Pid(9));
+    assert!(Criteria::AppId(OrFocused::Focused) < Criteria::Floating);
+    assert!(Criteria::Tiling < Criteria::Title(OrFocused::Focused));
+    assert_eq!(
+        (CriteriaList::pid(9) | CriteriaList::pid(10))
+            .canonical()
+            .to_string(),
+        r#"[pid="10" pid="9"]"#
+    );
+}
+
+#[test]
+fn criteria_display() {
+    assert_eq!(Criteria::Pid(42).to_string(), r#"pid="42""#);
+    assert_eq!(Criteria::ConId(5.into()).to_string(), r#"con_id="5""#);
+    assert_eq!(Criteria::ConMark("m".into()).to_string(), r#"con_mark="m""#);
+    assert_eq!(
+        Criteria::WindowRole(OrFocused::Focused).to_string(),
+        r#"window_role="__focused__""#
+    );
+    assert_eq!(
+        Criteria::WindowType(WindowType::Dialog).to_string(),
+        r#"window_type="dialog""#
+    );
+    assert_eq!(
+        Criteria::Workspace("1".to_string().into()).to_string(),
+        r#"workspace="1""#
+    );
+}
+
+#[test]
+fn parse_criteria() {
+    let list: CriteriaList = r#"[title="a b" pid=4  urgent="latest" con_id=__focused__]"#
+        .parse()
+        .unwrap();
+    assert_eq!(
+        list.to_string(),
+        r#"[title="a b" pid="4" urgent="latest" con_id="__focused__"]"#
+    );
+    let list: CriteriaList = r#"[title="a \"b\"" class="\\"]"#.parse().unwrap();
+    assert_eq!(list.len(), 2);
+    assert!(matches!(&list[0], Criteria::Title(OrFocused::Value(title)) if title == r#"a \"b\""#));
+    assert!(matches!(&list[1], Criteria::Class(OrFocused::Value(class)) if class == r"\\"));
+    assert_eq!(list.to_string(), r#"[title="a \"b\"" class="\\"]"#);
+    assert_eq!(
+        split_criteria(r#"[title="\"]\""] kill"#),
+        Some((r#"[title="\"]\""]"#, "kill"))
+    );
+    assert_eq!(
+        "[]".parse::<CriteriaList>().err(),
+        Some(CriteriaParseError::Empty)
+    );
+    assert_eq!(
+        r#"[title="a]"#.parse::<CriteriaList>().err(),
+        Some(CriteriaParseError::UnclosedQuote)
+    );
+    assert_eq!(
+        "[pid=x]".parse::<CriteriaList>().err(),
+        Some(CriteriaParseError::InvalidValue {
+            name: "pid".into(),
+            value: "x".into()
+        })
+    );
+    assert_eq!(
+        "[foo]".parse::<CriteriaList>().err(),
+        Some(CriteriaParseError::UnknownCriteria { name: "foo".into() })
+    );
+}
+
+#[test]
+fn criteria_after_display() {
+    let mut list = CriteriaList::floating();
+    assert_eq!(list.to_string(), "[floating]");
+    list.criteria(Criteria::Pid(3));
+    assert_eq!(list.to_string(), r#"[floating pid="3"]"#);
+    assert!(list == CriteriaList::floating() | Criteria::Pid(3));
+}
@@ -0,0 +1,168 @@
+use derive_more::{Display, Error, From};
+
+/// Any error returned by this crate
+///
+/// Every error type of this crate can be converted into this using `?`.
+#[derive(Debug, Display, Error, From)]
+#[non_exhaustive]
+pub enum SwayCommandError {
+    /// See [`ConfigParseError`]
+    ConfigParse(ConfigParseError),
+    /// See [`XkbOptionError`]
+    XkbOption(XkbOptionError),
+    /// See [`WorkspaceParseError`]
+    WorkspaceParse(WorkspaceParseError),
+    /// See [`CriteriaParseError`]
+    CriteriaParse(CriteriaParseError),
+    /// See [`SetVariableNameError`]
+    SetVariableName(SetVariableNameError),
+    /// See [`GapsDirectionParseError`]
+    GapsDirectionParse(GapsDirectionParseError),
+    /// See [`GapsModificationParseError`]
+    GapsModificationParse(GapsModificationParseError),
+    /// See [`GapsWorkspacesParseError`]
+    GapsWorkspacesParse(GapsWorkspacesParseError),
+    /// See [`CommandParseError`]
+    CommandParse(CommandParseError),
+    /// See [`TitlebarBorderThicknessError`]
+    TitlebarBorderThickness(TitlebarBorderThicknessError),
+    /// See [`IncludePathError`]
+    IncludePath(IncludePathError),
+    /// See [`IpcError`]
+    #[cfg(feature = "sway_ipc")]
+    Ipc(IpcError),
+}
+
+/// Error returned by [`CommandList::from_str_config`](crate::CommandList::from_str_config)
+#[derive(Debug, Display, Error, Clone, PartialEq, Eq)]
+pub enum ConfigParseError {
+    /// A block opened with `{` was never closed
+    #[display(fmt = "block opened in line {line} is never closed")]
+    UnclosedBlock {
+        /// Line the block was opened in (starting at 1)
+        line: usize,
+    },
+    /// A `}` without a matching `{`
+    #[display(fmt = "unexpected `}}` in line {line}")]
+    UnexpectedClosingBrace {
+        /// Line of the `}` (starting at 1)
+        line: usize,
+    },
+}
+
+/// Error returned when an [`XkbOption`](crate::commands::XkbOption) is not of
+/// the form `group:option`
+#[derive(Debug, Display, Error, Clone, PartialEq, Eq)]
+#[display(fmt = "invalid xkb option `{option}`, expected `group:option`")]
+pub struct XkbOptionError {
+    /// The invalid option
+    pub option: String,
+}
+
+/// Error returned when parsing an empty [`Workspace`](crate::commands::Workspace)
+/// or [`WorkspaceName`](crate::commands::WorkspaceName)
+#[derive(Debug, Display, Error, Clone, PartialEq, Eq)]
+#[display(fmt = "workspace name must not be empty")]
+pub struct WorkspaceParseError;
+
+/// Error returned when parsing a [`Criteria`](crate::criteria::Criteria) or
+/// [`CriteriaList`](crate::criteria::CriteriaList)
+#[derive(Debug, Display, Error, Clone, PartialEq, Eq)]
+pub enum CriteriaParseError {
+    /// The criteria list is not enclosed in `[` and `]`
+    #[display(fmt = "criteria list needs to be enclosed in `[` and `]`")]
+    MissingBrackets,
+    /// The criteria list does not contain any criteria
+    #[display(fmt = "criteria list must not be empty")]
+    Empty,
+    /// A `"` was opened but never closed
+    #[display(fmt = "unclosed `\"` in criteria list")]
+    UnclosedQuote,
+    /// The criteria is not known
+    #[display(fmt = "unknown criteria `{name}`")]
+    UnknownCriteria {
+        /// Name of the criteria
+        name: String,
+    },
+    /// The value is not valid for the criteria
+    #[display(fmt = "invalid value `{value}` for criteria `{name}`")]
+    InvalidValue {
+        /// Name of the criteria
+        name: String,
+        /// The invalid value
+        value: String,
+    },
+}
+
+/// Error returned by
+/// [`CriterialessCommand::try_set`](crate::commands::CriterialessCommand::try_set)
+/// when the variable name is not of the form `[a-zA-Z_][a-zA-Z0-9_]*`
+#[derive(Debug, Display, Error, Clone, PartialEq, Eq)]
+#[display(fmt = "invalid variable name `{name}`")]
+pub struct SetVariableNameError {
+    /// The invalid name
+    pub name: String,
+}
+
+/// Error returned by
+/// [`CriterialessCommand::try_titlebar_border_thickness`](crate::commands::CriterialessCommand::try_titlebar_border_thickness)
+/// when the thickness is 100 pixels or more
+#[derive(Debug, Display, Error, Clone, PartialEq, Eq)]
+#[display(fmt = "titlebar border thickness of {px} px is too large")]
+pub struct TitlebarBorderThicknessError {
+    /// The rejected thickness
+    pub px: u32,
+}
+
+/// Error returned by
+/// [`ConfigCommand::include`](crate::commands::ConfigCommand::include) and
+/// [`ConfigCommand::include_glob`](crate::commands::ConfigCommand::include_glob)
+/// when the path is empty
+#[derive(Debug, Display, Error, Clone, PartialEq, Eq)]
+#[display(fmt = "include path must not be empty")]
+pub struct IncludePathError;
+
+/// Error returned when parsing an unknown
+/// [`GapsDirection`](crate::commands::GapsDirection)
+#[derive(Debug, Display, Error, Clone, PartialEq, Eq)]
+#[display(fmt = "invalid gaps direction `{value}`")]
+pub struct GapsDirectionParseError {
+    /// The invalid value
+    pub value: String,
+}
+
+/// Error returned when parsing an unknown
+/// [`GapsModification`](crate::commands::GapsModification)
+#[derive(Debug, Display, Error, Clone, PartialEq, Eq)]
+#[display(fmt = "invalid gaps modification `{value}`")]
+pub struct GapsModificationParseError {
+    /// The invalid value
+    pub value: String,
+}
+
+/// Error returned when parsing an unknown
+/// [`GapsWorkspaces`](crate::commands::GapsWorkspaces)
+#[derive(Debug, Display, Error, Clone, PartialEq, Eq)]
+#[display(fmt = "invalid gaps workspaces `{value}`, expected `all` or `current`")]
+pub struct GapsWorkspacesParseError {
+    /// The invalid value
+    pub value: String,
+}
+
+/// Error returned when parsing a
+/// [`SubCommand`](crate::commands::SubCommand) or
+/// [`CriterialessCommand`](crate::commands::CriterialessCommand) that is
+/// unknown, not supported by the parser yet or has invalid arguments
+#[derive(Debug, Display, Error, Clone, PartialEq, Eq)]
+#[display(fmt = "unknown or unsupported command `{command}`")]
+pub struct CommandParseError {
+    /// The command that could not be parsed
+    pub command: String,
+}
+
+/// Error returned when communicating with sway fails, see
+/// [`SwaySocket`](crate::ipc::SwaySocket)
+#[cfg(feature = "sway_ipc")]
+#[derive(Debug, Display, Error, From)]
+#[display(fmt = "sway ipc failed: {_0}")]
+pub struct IpcError(#[error(source)] swayipc::Error);
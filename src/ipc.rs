@@ -0,0 +1,67 @@
+use swayipc::Connection;
+
+use crate::{error::IpcError, CommandList};
+
+/// Connection to the sway ipc socket
+///
+/// ```no_run
+/// # use sway_command::{*, commands::*, ipc::*};
+/// let mut socket = SwaySocket::new()?;
+/// let replies = socket.run_command(&CommandList::default().command(SubCommand::Reload))?;
+/// assert!(replies.iter().all(CommandReply::is_success));
+/// # Ok::<(), sway_command::error::IpcError>(())
+/// ```
+pub struct SwaySocket(Connection);
+
+/// Reply of sway for a single command
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandReply {
+    /// The error message if the command failed
+    pub error: Option<String>,
+}
+
+impl CommandReply {
+    /// Returns `true` if the command succeeded
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+impl SwaySocket {
+    /// Connects to the socket of the running sway instance
+    pub fn new() -> Result<Self, IpcError> {
+        Ok(Self(Connection::new()?))
+    }
+
+    /// Runs all commands in `list`, returning one reply per command
+    pub fn run_command(&mut self, list: &CommandList) -> Result<Vec<CommandReply>, IpcError> {
+        self.run_command_string(list.as_ref())
+    }
+
+    /// Runs each of `lists` over this connection, waiting for the replies of a
+    /// list before sending the next
+    ///
+    /// Stops at the first ipc error, command failures are reported in the
+    /// returned replies instead.
+    pub fn run_batch(
+        &mut self,
+        lists: &[&CommandList],
+    ) -> Result<Vec<Vec<CommandReply>>, IpcError> {
+        lists.iter().map(|list| self.run_command(list)).collect()
+    }
+
+    /// Runs `cmd` without any validation, e.g. a [`Command::Raw`](crate::Command::Raw)
+    ///
+    /// Multiple commands can be separated by `;` or `,`, sway returns one
+    /// reply per command.
+    pub fn run_command_string(&mut self, cmd: &str) -> Result<Vec<CommandReply>, IpcError> {
+        Ok(self
+            .0
+            .run_command(cmd)?
+            .into_iter()
+            .map(|reply| CommandReply {
+                error: reply.err().map(|e| e.to_string()),
+            })
+            .collect())
+    }
+}
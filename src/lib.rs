@@ -1,15 +1,30 @@
 #![warn(missing_docs)]
 //! Implements a builder for swaymsg.
-use std::vec;
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    slice,
+    str::FromStr,
+    sync::OnceLock,
+    vec,
+};
 
-use commands::{CriterialessCommand, SubCommand};
+use commands::{ConfigCommand, CriterialessCommand, SubCommand};
 use criteria::{Criteria, CriteriaList};
-use derive_more::{AsRef, Display, From};
+use derive_more::{AsRef, Deref, Display, From};
+use error::{ConfigParseError, CriteriaParseError};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// Contains the types for command creation
 pub mod commands;
 /// Contains the types for criteria creation
 pub mod criteria;
+/// Contains the error types
+pub mod error;
+/// Contains a connection to run commands via sway ipc
+#[cfg(feature = "sway_ipc")]
+pub mod ipc;
 
 // TODO make AsRef a feature (maybe)
 // Without it you'd just call `.to_string()`
@@ -17,14 +32,30 @@ pub mod criteria;
 // ideal performance wise, but maybe also doesn't matter as long as you don't
 // add criteria after the fact
 /// Create a command list able to be run via sway ipc
-#[derive(AsRef, Default)]
+///
+/// Derefs to the contained commands:
+/// ```
+/// # use sway_command::*;
+/// let cmd = CommandList::default().command("workspace 1").command("exec foot");
+/// assert_eq!(cmd.len(), 2);
+/// assert_eq!(cmd[1].to_string(), "exec foot");
+/// ```
+#[derive(Deref, Default)]
 pub struct CommandList {
-    // To be able to implement `AsRef<str>`
-    #[as_ref(forward)]
-    rep: String,
+    // To be able to implement `AsRef<str>`, cleared when the commands are
+    // accessed mutably and rebuild on the next access
+    rep: OnceLock<String>,
+    #[deref(forward)]
     commands: Vec<Command>,
+    // Whether the last command is a `Command::Raw` that is still written to
+    // through `fmt::Write`
+    writing: bool,
 }
 
+/// Collapses all runs of whitespace into a single space and trims both ends
+///
+/// Only intended for comparing generated commands in tests, prefer
+/// [`CommandList::normalized`].
 #[doc(hidden)]
 pub fn normalize_whitespace(value: impl AsRef<str>) -> String {
     value
@@ -38,9 +69,16 @@ impl CommandList {
     pub fn get_commands(&self) -> &[Command] {
         &self.commands
     }
+    /// Get the string representation with all runs of whitespace collapsed
+    /// into a single space
+    ///
+    /// This is meant for comparing command lists, as it also touches
+    /// whitespace inside of quoted arguments.
+    pub fn normalized(&self) -> String {
+        normalize_whitespace(self.rep())
+    }
     /// ```
     /// # use sway_command::*;
-    /// # use sway_command::normalize_whitespace;
     /// # use sway_command::commands::*;
     /// # use sway_command::criteria::*;
     /// let cmd = CommandList::default()
@@ -56,27 +94,458 @@ impl CommandList {
     ///         SymKey::key("a"),
     ///         SubCommand::Exit.into(),
     ///     ));
-    /// let cmd: &str = cmd.as_ref();
     /// assert_eq!(
-    ///     normalize_whitespace(cmd),
+    ///     cmd.normalized(),
     ///     "workspace 5;border none;[floating]floating disable;bindsym a exit"
     /// );
     /// ```
     pub fn command(mut self, command: impl Into<Command>) -> Self {
-        let command = command.into();
-        if !self.commands.is_empty() {
-            self.rep.push(';');
+        self.push(command.into());
+        self
+    }
+
+    /// Applies `f` to every command, rebuilding the list
+    ///
+    /// ```
+    /// # use sway_command::{*, commands::*, criteria::*};
+    /// let cmd = CommandList::default()
+    ///     .command(SubCommand::Floating(EnDisTog::Enable))
+    ///     .command("workspace 1")
+    ///     .map_commands(|command| match command {
+    ///         Command::Criteria(command) => command.criteria(Criteria::Tiling).into(),
+    ///         command => command,
+    ///     });
+    /// assert!(cmd == "[tiling]floating enable;workspace 1");
+    /// ```
+    pub fn map_commands(self, f: impl FnMut(Command) -> Command) -> CommandList {
+        self.commands.into_iter().map(f).collect()
+    }
+
+    /// Splits the list into the commands before `index` and the commands from
+    /// `index` on
+    ///
+    /// # Panics
+    /// Panics if `index > len`.
+    ///
+    /// ```
+    /// # use sway_command::*;
+    /// let cmd = CommandList::default().command("workspace 1").command("exec foot");
+    /// let (first, second) = cmd.split_at(1);
+    /// assert!(first == "workspace 1");
+    /// assert!(second == "exec foot");
+    /// ```
+    pub fn split_at(self, index: usize) -> (CommandList, CommandList) {
+        let mut first = self.commands;
+        let second = first.split_off(index);
+        (first.into_iter().collect(), second.into_iter().collect())
+    }
+
+    /// Inserts `command` at `index`, shifting all commands after it
+    ///
+    /// # Panics
+    /// Panics if `index > len`.
+    ///
+    /// ```
+    /// # use sway_command::*;
+    /// let mut cmd = CommandList::default().command("workspace 1").command("exec foot");
+    /// cmd.insert(1, "border none");
+    /// assert!(cmd == "workspace 1;border none;exec foot");
+    /// ```
+    pub fn insert(&mut self, index: usize, command: impl Into<Command>) {
+        self.rep.take();
+        self.writing = false;
+        self.commands.insert(index, command.into());
+    }
+
+    /// Sorts the commands lexicographically by their string representation,
+    /// see [`Command`]'s `Ord` implementation
+    ///
+    /// ```
+    /// # use sway_command::*;
+    /// let mut cmd = CommandList::default().command("workspace 1").command("exec foot");
+    /// cmd.sort();
+    /// assert!(cmd == "exec foot;workspace 1");
+    /// ```
+    pub fn sort(&mut self) {
+        self.rep.take();
+        self.writing = false;
+        self.commands.sort();
+    }
+
+    /// Adds a [`ConfigCommand`], only valid when writing a config file
+    ///
+    /// ```
+    /// # use sway_command::{*, commands::*};
+    /// let cmd = CommandList::default()
+    ///     .config_command(ConfigCommand::DefaultOrientation(DefaultOrientation::Auto));
+    /// assert!(cmd == "default_orientation auto");
+    /// ```
+    pub fn config_command(self, command: ConfigCommand) -> Self {
+        self.command(command)
+    }
+
+    /// Parses a sway config, e.g. the output of `swaymsg -t get_config`
+    ///
+    /// Every line is added as a separate command, parsed with
+    /// [`Command::parse`], lines ending in `\` are joined with the following
+    /// line. Empty lines and comments (lines starting with `#`) are skipped.
+    /// Blocks (`{ ... }`) are kept together in a single [`Command::Raw`].
+    /// Braces inside of quotes or comments do not open or close blocks.
+    ///
+    /// ```
+    /// # use sway_command::*;
+    /// let cmd = CommandList::from_str_config(
+    ///     "# comment
+    /// set $mod Mod4
+    ///
+    /// bindsym $mod+Return \\
+    ///     exec foot
+    /// for_window [title=\"{\"] floating enable
+    /// bar {
+    ///     #position bottom
+    ///     position top
+    /// }",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(cmd.len(), 4);
+    /// assert!(matches!(cmd[0], Command::Criterialess(_)));
+    /// assert_eq!(cmd[1].to_string(), "bindsym $mod+Return exec foot");
+    /// assert_eq!(cmd[2].to_string(), r#"for_window [title="{"] floating enable"#);
+    /// assert_eq!(cmd[3].to_string(), "bar {\n    position top\n}");
+    /// ```
+    pub fn from_str_config(s: &str) -> Result<CommandList, ConfigParseError> {
+        let mut list = CommandList::default();
+        let mut current = String::new();
+        let mut continued = false;
+        let mut depth = 0usize;
+        let mut block_start = None;
+        for (index, line) in s.lines().enumerate() {
+            let trimmed = line.trim();
+            if !continued && (trimmed.is_empty() || trimmed.starts_with('#')) {
+                continue;
+            }
+            for brace in braces(trimmed) {
+                if brace == '{' {
+                    if depth == 0 {
+                        block_start = Some(index + 1);
+                    }
+                    depth += 1;
+                } else {
+                    depth = depth
+                        .checked_sub(1)
+                        .ok_or(ConfigParseError::UnexpectedClosingBrace { line: index + 1 })?;
+                }
+            }
+            if !continued && !current.is_empty() {
+                // Keep the indentation of lines inside of blocks
+                current.push_str(&line[..line.len() - line.trim_start().len()]);
+            }
+            if let Some(content) = trimmed.strip_suffix('\\') {
+                current.push_str(content.trim_end());
+                current.push(' ');
+                continued = true;
+                continue;
+            }
+            continued = false;
+            current.push_str(trimmed);
+            if depth > 0 {
+                current.push('\n');
+            } else if block_start.take().is_some() {
+                list.push(Command::Raw(std::mem::take(&mut current)));
+            } else {
+                list.push(Command::parse(&std::mem::take(&mut current)));
+            }
+        }
+        if let Some(line) = block_start.filter(|_| depth > 0) {
+            return Err(ConfigParseError::UnclosedBlock { line });
+        }
+        if !current.is_empty() {
+            list.push(Command::parse(&current));
+        }
+        Ok(list)
+    }
+
+    fn push(&mut self, command: Command) {
+        self.writing = false;
+        if let Some(rep) = self.rep.get_mut() {
+            if !self.commands.is_empty() {
+                rep.push(';');
+            }
+            rep.push_str(command.to_string().as_ref());
         }
-        self.rep.push_str(command.to_string().as_ref());
         self.commands.push(command);
-        self
+    }
+
+    fn rep(&self) -> &str {
+        self.rep.get_or_init(|| {
+            self.commands
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(";")
+        })
+    }
+}
+
+/// The braces of a config line, skipping quoted strings and comments
+fn braces(line: &str) -> impl Iterator<Item = char> + '_ {
+    let mut quote = None;
+    let mut escaped = false;
+    let mut previous = ' ';
+    line.chars()
+        .map_while(move |c| {
+            let brace = match (quote, c) {
+                _ if escaped => {
+                    escaped = false;
+                    None
+                }
+                (_, '\\') => {
+                    escaped = true;
+                    None
+                }
+                (Some(open), c) => {
+                    if c == open {
+                        quote = None;
+                    }
+                    None
+                }
+                (None, '"' | '\'') => {
+                    quote = Some(c);
+                    None
+                }
+                (None, '#') if previous.is_whitespace() => return None,
+                (None, '{' | '}') => Some(c),
+                _ => None,
+            };
+            previous = c;
+            Some(brace)
+        })
+        .flatten()
+}
+
+/// Shows the number of commands and the string representation
+///
+/// ```
+/// # use sway_command::*;
+/// let cmd = CommandList::default().command("workspace 1").command("exec foot");
+/// assert_eq!(
+///     format!("{cmd:?}"),
+///     r#"CommandList { count: 2, commands: "workspace 1;exec foot" }"#
+/// );
+/// ```
+impl fmt::Debug for CommandList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CommandList")
+            .field("count", &self.commands.len())
+            .field("commands", &self.rep())
+            .finish()
+    }
+}
+
+impl AsRef<str> for CommandList {
+    fn as_ref(&self) -> &str {
+        self.rep()
+    }
+}
+
+impl<'a> IntoIterator for &'a CommandList {
+    type IntoIter = slice::Iter<'a, Command>;
+    type Item = &'a Command;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.commands.iter()
+    }
+}
+
+/// Allows modifying the commands in place
+///
+/// ```
+/// # use sway_command::*;
+/// let mut cmd = CommandList::default().command("exec foot").command("reload");
+/// for command in &mut cmd {
+///     if let Command::Raw(raw) = command {
+///         *raw = raw.replace("exec", "exec_always");
+///     }
+/// }
+/// assert!(cmd == "exec_always foot;reload");
+/// ```
+impl<'a> IntoIterator for &'a mut CommandList {
+    type IntoIter = slice::IterMut<'a, Command>;
+    type Item = &'a mut Command;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.rep.take();
+        self.writing = false;
+        self.commands.iter_mut()
+    }
+}
+
+impl FromIterator<Command> for CommandList {
+    fn from_iter<T: IntoIterator<Item = Command>>(iter: T) -> Self {
+        let mut list = CommandList::default();
+        for command in iter {
+            list.push(command);
+        }
+        list
+    }
+}
+
+/// Serialized as a list of [`Command`]s
+#[cfg(feature = "serde")]
+impl Serialize for CommandList {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.commands.serialize(serializer)
+    }
+}
+
+/// Deserialized from a list of [`Command`]s
+///
+/// ```
+/// # use sway_command::*;
+/// let cmd: CommandList =
+///     serde_json::from_str(r#"["workspace 1", "exec foot"]"#).unwrap();
+/// assert!(cmd == "workspace 1;exec foot");
+/// ```
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for CommandList {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Vec::<Command>::deserialize(deserializer)?
+            .into_iter()
+            .collect())
+    }
+}
+
+/// Written text is added as [`Command::Raw`]s, separated by `;` or newlines
+/// outside of quotes
+///
+/// Every `write!` or `writeln!` ends the command written last, while text
+/// written through [`write_str`](fmt::Write::write_str) or
+/// [`write_char`](fmt::Write::write_char) is joined until the next separator.
+///
+/// ```
+/// # use sway_command::*;
+/// use std::fmt::Write;
+///
+/// let mut cmd = CommandList::default();
+/// write!(cmd, "workspace {}", 1).unwrap();
+/// writeln!(cmd, "exec {}", "foot").unwrap();
+/// write!(cmd, "border none; exec sh -c 'a; b'").unwrap();
+/// assert!(cmd == "workspace 1;exec foot;border none;exec sh -c 'a; b'");
+/// ```
+impl fmt::Write for CommandList {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut pending = String::new();
+        if self.writing {
+            if let Some(Command::Raw(raw)) = self.commands.pop() {
+                self.rep.take();
+                pending = raw;
+            }
+        }
+        pending.push_str(s);
+        let parts = split_unquoted(&pending, &[';', '\n']);
+        let (open, closed) = parts.split_last().expect("split returns at least one part");
+        for part in closed.iter().map(|part| part.trim()) {
+            if !part.is_empty() {
+                self.push(Command::Raw(part.to_owned()));
+            }
+        }
+        let open = open.trim_start();
+        if !open.is_empty() {
+            self.push(Command::Raw(open.to_owned()));
+            self.writing = true;
+        }
+        Ok(())
+    }
+
+    fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> fmt::Result {
+        let result = fmt::write(self, args);
+        if self.writing {
+            if let Some(Command::Raw(raw)) = self.commands.last_mut() {
+                let len = raw.trim_end().len();
+                if len < raw.len() {
+                    self.rep.take();
+                    raw.truncate(len);
+                }
+            }
+            self.writing = false;
+        }
+        result
+    }
+}
+
+/// Compares the string representation
+impl PartialEq for CommandList {
+    fn eq(&self, other: &Self) -> bool {
+        self.rep() == other.rep()
+    }
+}
+
+impl Eq for CommandList {}
+
+/// Hashes the string representation, so command lists that compare equal
+/// have the same hash
+///
+/// The hash is not stable across versions of this crate.
+///
+/// The string representation is cached in a cell, so it can be rebuilt lazily
+/// after mutable access, e.g. through `&mut CommandList` iteration, while
+/// still being borrowed by [`AsRef<str>`]. The cache only ever holds the
+/// representation of the current commands, so the hash of a key never changes
+/// while it is borrowed immutably. Clippy cannot see this and reports
+/// `mutable_key_type` for e.g. `HashSet<CommandList>`; add
+/// `"sway_command::CommandList"` (and `"sway_command::criteria::CriteriaList"`
+/// for [`CriteriaCommand`]) to `ignore-interior-mutability` in your
+/// `clippy.toml` to silence it.
+impl Hash for CommandList {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.rep().hash(state);
+    }
+}
+
+impl PartialEq<str> for CommandList {
+    fn eq(&self, other: &str) -> bool {
+        self.rep() == other
+    }
+}
+
+impl PartialEq<&str> for CommandList {
+    fn eq(&self, other: &&str) -> bool {
+        self.rep() == *other
+    }
+}
+
+impl PartialEq<String> for CommandList {
+    fn eq(&self, other: &String) -> bool {
+        self.rep() == other
     }
 }
 
 // TODO https://github.com/JelteF/derive_more/issues/219
 // #[derive(AsRef)]
 /// A Command that can be added to a [`CommandList`] or run directly
+///
+/// Usually this is created through one of the `From` conversions:
+/// - [`SubCommand`] and [`CriteriaCommand`] become [`Command::Criteria`], a
+///   [`SubCommand`] is wrapped in a [`CriteriaCommand`] without criteria
+/// - [`CriterialessCommand`] becomes [`Command::Criterialess`], boxing it
+/// - [`ConfigCommand`] becomes [`Command::Config`], boxing it
+/// - `String` and `&str` become [`Command::Raw`]
+///
+/// ```
+/// # use sway_command::{*, commands::*};
+/// let command: Command = CriterialessCommand::Exec("foot".into()).into();
+/// assert!(matches!(command, Command::Criterialess(_)));
+/// let command: Command = SubCommand::Reload.into();
+/// assert!(matches!(command, Command::Criteria(_)));
+/// ```
+///
+/// With the `serde` feature, [`Command::Raw`] is (de)serialized as a plain
+/// string, e.g. `"workspace 1"`, all other commands with the variant name as
+/// key, e.g. `{"criterialess": {"exec": "foot"}}` or
+/// `{"criteria": {"criteria": [...], "commands": [...]}}`.
 #[derive(Display, From)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Command {
     // #[as_ref(forward)]
     /// A Command that contains criteria
@@ -85,23 +554,149 @@ pub enum Command {
     /// A Command without Criteria
     #[from(types(CriterialessCommand))]
     Criterialess(Box<CriterialessCommand>),
+    /// A Command that may only be used in the configuration file
+    #[from(types(ConfigCommand))]
+    Config(Box<ConfigCommand>),
     // #[from(types("&str"))]
     /// Untyped Command
     #[from(forward)]
+    #[cfg_attr(feature = "serde", serde(untagged))]
     Raw(String),
 }
 
-#[derive(AsRef, Display, Default, Clone)]
+impl Command {
+    /// Parses a single command, falling back to [`Command::Raw`] if it is not
+    /// supported by the [`CriterialessCommand`] and [`CriteriaCommand`]
+    /// parsers
+    ///
+    /// ```
+    /// # use sway_command::*;
+    /// assert!(matches!(Command::parse("exec foot"), Command::Criterialess(_)));
+    /// assert!(matches!(Command::parse("floating enable"), Command::Criteria(_)));
+    /// assert!(matches!(
+    ///     Command::parse("[floating] sticky enable, border none"),
+    ///     Command::Criteria(_)
+    /// ));
+    /// assert!(matches!(Command::parse("bar { }"), Command::Raw(_)));
+    /// assert_eq!(Command::parse(" kill ").to_string(), "kill");
+    /// ```
+    pub fn parse(s: &str) -> Command {
+        let s = s.trim();
+        if let Ok(command) = s.parse::<CriterialessCommand>() {
+            return command.into();
+        }
+        match s.parse::<CriteriaCommand>() {
+            Ok(command)
+                if command.is_valid()
+                    && !command
+                        .iter()
+                        .any(|command| matches!(command, SubCommand::Raw(_))) =>
+            {
+                command.into()
+            }
+            _ => Command::Raw(s.to_owned()),
+        }
+    }
+}
+
+/// Compares the string representation, which also avoids comparing the
+/// floating point values some commands contain
+impl PartialEq for Command {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for Command {}
+
+/// Orders commands lexicographically by their string representation
+///
+/// This is not a semantic ordering, it only allows producing deterministic
+/// output, e.g. through [`CommandList::sort`].
+impl PartialOrd for Command {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Command {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.to_string().cmp(&other.to_string())
+    }
+}
+
+#[derive(AsRef, Deref, Display, Default, Clone)]
 #[display(fmt = "{rep}")]
 /// A command with an optional Criteria
+///
+/// [`CriteriaCommand::default`] is the starting point for the builder, it
+/// displays as an empty string and is only a valid command once at least one
+/// [`SubCommand`] was added, see [`CriteriaCommand::is_valid`].
+///
+/// Derefs to the contained [`SubCommand`]s.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(from = "CriteriaCommandParts", into = "CriteriaCommandParts")
+)]
 pub struct CriteriaCommand {
     // To be able to implement `AsRef<str>`
     #[as_ref(forward)]
     rep: String,
     criteria: Option<CriteriaList>,
+    #[deref(forward)]
     commands: Vec<SubCommand>,
 }
 
+/// (De)serialization format of [`CriteriaCommand`], `rep` is rebuild when
+/// deserializing
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct CriteriaCommandParts {
+    criteria: Option<CriteriaList>,
+    commands: Vec<SubCommand>,
+}
+
+#[cfg(feature = "serde")]
+impl From<CriteriaCommandParts> for CriteriaCommand {
+    fn from(CriteriaCommandParts { criteria, commands }: CriteriaCommandParts) -> Self {
+        Self::from_parts(criteria, commands)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<CriteriaCommand> for CriteriaCommandParts {
+    fn from(
+        CriteriaCommand {
+            criteria, commands, ..
+        }: CriteriaCommand,
+    ) -> Self {
+        Self { criteria, commands }
+    }
+}
+
+/// Compares the string representation
+impl PartialEq for CriteriaCommand {
+    fn eq(&self, other: &Self) -> bool {
+        self.rep == other.rep
+    }
+}
+
+impl Eq for CriteriaCommand {}
+
+/// Hashes the string representation, so commands that compare equal have the
+/// same hash
+///
+/// The hash is not stable across versions of this crate. The contained
+/// [`CriteriaList`] caches its representation in a cell, see
+/// [`CommandList`'s `Hash`](CommandList#impl-Hash-for-CommandList) on how to
+/// silence clippy's `mutable_key_type` lint.
+impl Hash for CriteriaCommand {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.rep.hash(state);
+    }
+}
+
 impl From<SubCommand> for CriteriaCommand {
     fn from(cmd: SubCommand) -> Self {
         Self {
@@ -112,11 +707,131 @@ impl From<SubCommand> for CriteriaCommand {
     }
 }
 
+/// ```
+/// # use sway_command::{*, commands::*, criteria::*};
+/// let cmd: CriteriaCommand = (CriteriaList::floating(), SubCommand::Sticky(EnDisTog::Enable)).into();
+/// assert_eq!(cmd.to_string(), "[floating]sticky enable");
+/// ```
+impl From<(CriteriaList, SubCommand)> for CriteriaCommand {
+    fn from((criteria, command): (CriteriaList, SubCommand)) -> Self {
+        Self::from_parts(Some(criteria), vec![command])
+    }
+}
+
+/// ```
+/// # use sway_command::{*, commands::*, criteria::*};
+/// let cmd: CriteriaCommand = (
+///     CriteriaList::floating(),
+///     vec![SubCommand::Sticky(EnDisTog::Enable), SubCommand::Border(Border::None)],
+/// )
+/// .into();
+/// assert_eq!(cmd.to_string(), "[floating]sticky enable,border none");
+/// ```
+impl From<(CriteriaList, Vec<SubCommand>)> for CriteriaCommand {
+    fn from((criteria, commands): (CriteriaList, Vec<SubCommand>)) -> Self {
+        Self::from_parts(Some(criteria), commands)
+    }
+}
+
+/// Parses an optional criteria list followed by comma separated sub commands
+///
+/// Sub commands that cannot be parsed (yet) are kept as [`SubCommand::Raw`].
+///
+/// ```
+/// # use sway_command::{*, commands::*};
+/// let cmd: CriteriaCommand = r#"[app_id="firefox"] floating enable, border pixel 2"#.parse()?;
+/// assert_eq!(cmd.len(), 2);
+/// assert!(matches!(cmd[0], SubCommand::Floating(EnDisTog::Enable)));
+/// assert!(matches!(cmd[1], SubCommand::Border(Border::Pixel(Some(2)))));
+/// assert_eq!(
+///     cmd.to_string(),
+///     r#"[app_id="firefox"]floating enable,border pixel 2"#
+/// );
+/// let cmd = CriteriaCommand::try_from("opacity set 0.5".to_owned())?;
+/// assert!(matches!(&cmd[0], SubCommand::Raw(raw) if raw == "opacity set 0.5"));
+/// assert!("[unknown] kill".parse::<CriteriaCommand>().is_err());
+/// # Ok::<(), sway_command::error::CriteriaParseError>(())
+/// ```
+impl FromStr for CriteriaCommand {
+    type Err = CriteriaParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (criteria, commands) = match criteria::split_criteria(s) {
+            Some((criteria, commands)) => (Some(criteria.parse()?), commands),
+            None if s.starts_with('[') => return Err(CriteriaParseError::MissingBrackets),
+            None => (None, s),
+        };
+        let commands = split_unquoted(commands, &[','])
+            .into_iter()
+            .map(str::trim)
+            .filter(|command| !command.is_empty())
+            .map(|command| {
+                command
+                    .parse()
+                    .unwrap_or_else(|_| SubCommand::Raw(command.to_owned()))
+            })
+            .collect();
+        Ok(Self::from_parts(criteria, commands))
+    }
+}
+
+impl TryFrom<String> for CriteriaCommand {
+    type Error = CriteriaParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<&str> for CriteriaCommand {
+    type Error = CriteriaParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// Splits `s` at every one of `separators` that is neither inside of quotes
+/// nor escaped
+fn split_unquoted<'a>(s: &'a str, separators: &[char]) -> Vec<&'a str> {
+    let mut parts = Vec::new();
+    let mut quote = None;
+    let mut escaped = false;
+    let mut start = 0;
+    for (idx, c) in s.char_indices() {
+        match (quote, c) {
+            _ if escaped => escaped = false,
+            (_, '\\') => escaped = true,
+            (Some(open), c) if c == open => quote = None,
+            (None, '"' | '\'') => quote = Some(c),
+            (None, c) if separators.contains(&c) => {
+                parts.push(&s[start..idx]);
+                start = idx + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(&s[start..]);
+    parts
+}
+
 impl CriteriaCommand {
     /// Get the commands in CriteriaCommand
     pub fn get_commands(&self) -> &[SubCommand] {
         &self.commands
     }
+    /// Whether this contains at least one command
+    ///
+    /// ```
+    /// # use sway_command::{*, commands::*, criteria::*};
+    /// let cmd = CriteriaCommand::default().criteria(Criteria::Floating);
+    /// assert!(!cmd.is_valid());
+    /// assert!(cmd.command(SubCommand::Border(Border::None)).is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        !self.commands.is_empty()
+    }
     /// At a new command
     pub fn command(mut self, command: SubCommand) -> Self {
         if !self.commands.is_empty() {
@@ -126,6 +841,54 @@ impl CriteriaCommand {
         self.commands.push(command);
         self
     }
+    /// Merges two commands into one
+    ///
+    /// The criteria of both are combined, so the merged command only applies to
+    /// views matching all criteria. If only one of them has criteria, those are
+    /// used. The sub commands of `other` are run after the ones of `self`.
+    ///
+    /// ```
+    /// # use sway_command::{*, commands::*, criteria::*};
+    /// let floating = CriteriaCommand::default()
+    ///     .criteria(Criteria::Floating)
+    ///     .command(SubCommand::Border(Border::None));
+    /// let sticky = CriteriaCommand::default()
+    ///     .criteria(Criteria::focused_app_id())
+    ///     .command(SubCommand::Sticky(EnDisTog::Enable));
+    /// assert_eq!(
+    ///     floating.and(sticky).to_string(),
+    ///     r#"[floating app_id="__focused__"]border none,sticky enable"#
+    /// );
+    /// ```
+    pub fn and(self, other: CriteriaCommand) -> CriteriaCommand {
+        let criteria = match (self.criteria, other.criteria) {
+            (Some(criteria), Some(other)) => Some(criteria | other),
+            (criteria, other) => criteria.or(other),
+        };
+        let mut commands = self.commands;
+        commands.extend(other.commands);
+        Self::from_parts(criteria, commands)
+    }
+
+    fn from_parts(criteria: Option<CriteriaList>, commands: Vec<SubCommand>) -> Self {
+        let mut rep = criteria
+            .as_ref()
+            .map(ToString::to_string)
+            .unwrap_or_default();
+        rep.push_str(
+            &commands
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+        Self {
+            rep,
+            criteria,
+            commands,
+        }
+    }
+
     /// Preformance note:
     ///
     /// When adding criteria after adding the first commands, the string
@@ -133,11 +896,11 @@ impl CriteriaCommand {
     pub fn criteria(mut self, criteria: Criteria) -> Self {
         if self.commands.is_empty() && self.criteria.is_some() {
             let Some(criterias) = &mut self.criteria else { unreachable!() };
-            criterias.criteria(criteria);
             // TODO investigate if this could be replaced with `self.rep =
             // criterias.to_string()`
             assert_eq!(self.rep.pop(), Some(']'));
-            self.rep.push_str(" {criteria}]")
+            self.rep.push_str(&format!(" {criteria}]"));
+            criterias.criteria(criteria);
         } else {
             if let Some(criterias) = &mut self.criteria {
                 criterias.criteria(criteria);
@@ -160,3 +923,214 @@ impl CriteriaCommand {
         self
     }
 }
+
+#[test]
+fn command_list_eq_str() {
+    let cmd = CommandList::default()
+        .command("workspace 1")
+        .command("exec alacritty");
+    assert!(cmd == "workspace 1;exec alacritty");
+    let expected = String::from("workspace 1;exec alacritty");
+    assert!(cmd == expected);
+    assert!(cmd != *"workspace 1");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trip() {
+    use commands::*;
+
+    let command: Command = CriteriaCommand::default()
+        .criteria(Criteria::Floating)
+        .command(SubCommand::Border(Border::Pixel(Some(2))))
+        .command(SubCommand::Floating(EnDisTog::Toggle))
+        .into();
+    let json = serde_json::to_string(&command).unwrap();
+    let deserialized: Command = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized.to_string(), command.to_string());
+
+    let command: Command = CriterialessCommand::Exec("alacritty".into()).into();
+    let json = serde_json::to_string(&command).unwrap();
+    let deserialized: Command = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized.to_string(), "exec alacritty");
+
+    let deserialized: Command = serde_json::from_str(r#""workspace 5""#).unwrap();
+    assert_eq!(deserialized.to_string(), "workspace 5");
+    assert_eq!(
+        serde_json::to_string(&deserialized).unwrap(),
+        r#""workspace 5""#
+    );
+
+    let deserialized: CommandList =
+        serde_json::from_str(r#"["workspace 1", {"criterialess": {"exec": "foot"}}]"#).unwrap();
+    assert!(deserialized == "workspace 1;exec foot");
+
+    let list = CommandList::default()
+        .command(SubCommand::Reload)
+        .command(CriterialessCommand::Exec("foot".into()));
+    let json = serde_json::to_string(&list).unwrap();
+    let deserialized: CommandList = serde_json::from_str(&json).unwrap();
+    assert!(deserialized == list);
+}
+
+#[test]
+fn from_str_config_errors() {
+    use error::ConfigParseError;
+
+    assert_eq!(
+        CommandList::from_str_config("bar {\n  position top\n").err(),
+        Some(ConfigParseError::UnclosedBlock { line: 1 })
+    );
+    assert_eq!(
+        CommandList::from_str_config("exec foot\n}").err(),
+        Some(ConfigParseError::UnexpectedClosingBrace { line: 2 })
+    );
+}
+
+#[test]
+fn from_str_config_quotes_and_comments() {
+    let cmd = CommandList::from_str_config("bindsym $mod+x exec sh -c 'echo }'").unwrap();
+    assert!(cmd == "bindsym $mod+x exec sh -c 'echo }'");
+    let cmd = CommandList::from_str_config(r#"for_window [title="{"] floating enable"#).unwrap();
+    assert!(matches!(cmd[0], Command::Criterialess(_)));
+    let cmd = CommandList::from_str_config("exec foot # {\nexec \"\\\"}\"").unwrap();
+    assert_eq!(cmd.len(), 2);
+}
+
+#[test]
+fn from_str_config_blocks() {
+    let cmd = CommandList::from_str_config(
+        "mode resize {
+    # shrink
+
+    bindsym h \\
+        resize shrink width 10px
+    bindsym Escape mode default
+}",
+    )
+    .unwrap();
+    assert_eq!(cmd.len(), 1);
+    assert_eq!(
+        cmd[0].to_string(),
+        "mode resize {\n    bindsym h resize shrink width 10px\n    bindsym Escape mode default\n}"
+    );
+}
+
+#[test]
+fn from_str_config_typed() {
+    let cmd = CommandList::from_str_config(
+        "floating_modifier $mod normal
+exec foot
+focus left
+move container to workspace 2",
+    )
+    .unwrap();
+    assert!(matches!(cmd[0], Command::Raw(_)));
+    assert!(matches!(cmd[1], Command::Criterialess(_)));
+    assert!(matches!(cmd[2], Command::Criteria(_)));
+    assert!(matches!(cmd[3], Command::Criteria(_)));
+    assert_eq!(cmd[3].to_string(), "move container to workspace 2");
+}
+
+#[test]
+fn command_eq() {
+    let command: Command = SubCommand::Reload.into();
+    assert!(command == Command::Raw("reload".into()));
+    assert!(command != Command::Raw("exit".into()));
+}
+
+#[test]
+fn hash_command_list() {
+    use std::collections::HashSet;
+
+    let set: HashSet<_> = [
+        CommandList::default().command("workspace 1"),
+        CommandList::default().command("workspace 1"),
+        CommandList::default().command("workspace 2"),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(set.len(), 2);
+
+    let set: HashSet<_> = [
+        CriteriaCommand::from(SubCommand::Reload),
+        CriteriaCommand::default().command(SubCommand::Reload),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(set.len(), 1);
+}
+
+#[test]
+fn criterialess_into_command() {
+    use commands::*;
+
+    for (command, expected) in [
+        (CriterialessCommand::Exec("foot".into()), "exec foot"),
+        (
+            CriterialessCommand::ExecAlways("foot".into()),
+            "exec_always foot",
+        ),
+        (CriterialessCommand::Kill, "kill"),
+        (CriterialessCommand::ShowMarks(YesNo::No), "show_marks no"),
+        (
+            CriterialessCommand::TilingDragThreshold(9),
+            "tiling_drag_threshold 9",
+        ),
+    ] {
+        let command: Command = command.into();
+        assert!(matches!(command, Command::Criterialess(_)));
+        assert_eq!(command.to_string(), expected);
+    }
+}
+
+#[test]
+fn criteria_command_multiple_criteria() {
+    let cmd = CriteriaCommand::default()
+        .criteria(Criteria::Floating)
+        .criteria(Criteria::Pid(3));
+    assert_eq!(cmd.to_string(), r#"[floating pid="3"]"#);
+    let cmd = cmd.command(SubCommand::Sticky(commands::EnDisTog::Enable));
+    assert_eq!(cmd.to_string(), r#"[floating pid="3"]sticky enable"#);
+    let cmd = cmd.criteria(Criteria::Tiling);
+    assert_eq!(cmd.to_string(), r#"[floating pid="3" tiling]sticky enable"#);
+}
+
+#[test]
+fn criteria_command_from_str() {
+    let cmd: CriteriaCommand = r#"[title="a, b]"] title_format "%title, %app_id", kill"#
+        .parse()
+        .unwrap();
+    assert_eq!(cmd.len(), 2);
+    assert!(matches!(&cmd[0], SubCommand::TitleFormat(format) if format == r#""%title, %app_id""#));
+    assert!(matches!(&cmd[1], SubCommand::Raw(raw) if raw == "kill"));
+    let cmd: CriteriaCommand = r#"[title="\""] title_format "\", %title", kill"#.parse().unwrap();
+    assert_eq!(cmd.len(), 2);
+    assert!(!CriteriaCommand::try_from("[floating]").unwrap().is_valid());
+    assert_eq!(
+        CriteriaCommand::try_from(r#"[title="x" kill"#).err(),
+        Some(error::CriteriaParseError::MissingBrackets)
+    );
+}
+
+#[test]
+fn fmt_write() {
+    use std::fmt::Write;
+
+    let mut cmd = CommandList::default();
+    for c in "exec foot".chars() {
+        cmd.write_char(c).unwrap();
+    }
+    cmd.write_char(';').unwrap();
+    cmd.write_str("bor").unwrap();
+    cmd.write_str("der none\nkill").unwrap();
+    assert!(cmd == "exec foot;border none;kill");
+    assert_eq!(cmd.len(), 3);
+
+    let mut cmd = CommandList::default();
+    writeln!(cmd, "exec {}", "foot").unwrap();
+    writeln!(cmd, "workspace 1 ").unwrap();
+    write!(cmd, "{}", SubCommand::Reload).unwrap();
+    assert!(cmd == "exec foot;workspace 1;reload");
+    assert!(matches!(&cmd[1], Command::Raw(raw) if raw == "workspace 1"));
+}
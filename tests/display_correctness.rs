@@ -0,0 +1,759 @@
+//! Compares the string representation of every variant with the sway syntax
+//! documented in sway(5), sway-input(5) and sway-output(5)
+//!
+//! The output is compared exactly, so neither stray separators nor additional
+//! whitespace are hidden.
+
+use std::{fmt::Display, num::NonZeroU32};
+
+use sway_command::{
+    commands::*,
+    criteria::{self, Criteria, CriteriaList, OrFocused, WindowType},
+    Command,
+};
+use vec1::vec1;
+
+#[track_caller]
+fn check(value: impl Display, expected: &str) {
+    assert_eq!(value.to_string(), expected);
+}
+
+fn exec(command: &str) -> Command {
+    CriterialessCommand::Exec(command.into()).into()
+}
+
+#[test]
+fn workspace() {
+    let name = || WorkspaceName::Simple("web".into());
+    check(WorkspaceName::WithNumber(2, "mail".into()), "2:mail");
+    check(Workspace::Name(name()), "web");
+    check(Workspace::Number(name()), "number web");
+    check(Workspace::Prev, "prev");
+    check(Workspace::Next, "next");
+    check(Workspace::Current, "current");
+    check(Workspace::PrevOnOutput, "prev_on_output");
+    check(Workspace::NextOnOutput, "next_on_output");
+    check(Workspace::BackAndForth, "back_and_forth");
+}
+
+#[test]
+fn output() {
+    check(Output::Up, "up");
+    check(Output::Right, "right");
+    check(Output::Down, "down");
+    check(Output::Left, "left");
+    check(Output::Current, "current");
+    check(Output::Name("DP-1".into()), "DP-1");
+}
+
+#[test]
+fn shared_enums() {
+    for (direction, name) in [
+        (GapsDirection::Inner, "inner"),
+        (GapsDirection::Outer, "outer"),
+        (GapsDirection::Horizontal, "horizontal"),
+        (GapsDirection::Vertical, "vertical"),
+        (GapsDirection::Top, "top"),
+        (GapsDirection::Right, "right"),
+        (GapsDirection::Bottom, "bottom"),
+        (GapsDirection::Left, "left"),
+    ] {
+        check(direction, name);
+    }
+    check(YesNo::Yes, "yes");
+    check(YesNo::No, "no");
+    check(EnDisable::Enable, "enable");
+    check(EnDisable::Disable, "disable");
+    check(EnDisTog::Enable, "enable");
+    check(EnDisTog::Disable, "disable");
+    check(EnDisTog::Toggle, "toggle");
+}
+
+#[test]
+fn sub_command() {
+    check(SubCommand::Border(Border::None), "border none");
+    check(SubCommand::Border(Border::Normal(None)), "border normal");
+    check(
+        SubCommand::Border(Border::Normal(Some(2))),
+        "border normal 2",
+    );
+    check(
+        SubCommand::Border(Border::ClientSideDecorations),
+        "border csd",
+    );
+    check(SubCommand::Border(Border::Pixel(Some(1))), "border pixel 1");
+    check(SubCommand::Border(Border::Toggle), "border toggle");
+    check(SubCommand::Exit, "exit");
+    check(SubCommand::Floating(EnDisTog::Toggle), "floating toggle");
+    check(
+        SubCommand::InhibitIdle(InhibitIdle::Fullscreen),
+        "inhibit_idle fullscreen",
+    );
+    check(
+        SubCommand::MaxRenderTime(MaxRenderTime::Off),
+        "max_render_time off",
+    );
+    check(SubCommand::Nop(None), "nop");
+    check(SubCommand::Nop(Some("comment".into())), "nop comment");
+    check(SubCommand::Reload, "reload");
+    check(
+        SubCommand::RenameFocusedWorkspace("web".into()),
+        "rename workspace to web",
+    );
+    check(SubCommand::ScratchpadShow, "scratchpad show");
+    check(
+        SubCommand::ShortcutsInhibitor(EnDisable::Disable),
+        "shortcuts_inhibitor disable",
+    );
+    check(SubCommand::Split(Split::Toggle), "split toggle");
+    check(SubCommand::Sticky(EnDisTog::Enable), "sticky enable");
+    check(
+        SubCommand::TitleFormat("%title".into()),
+        "title_format %title",
+    );
+    check(SubCommand::Raw("opacity set 0.5".into()), "opacity set 0.5");
+}
+
+#[test]
+fn focus() {
+    for (focus, expected) in [
+        (Focus::This, "focus"),
+        (Focus::Up, "focus up"),
+        (Focus::Right, "focus right"),
+        (Focus::Down, "focus down"),
+        (Focus::Left, "focus left"),
+        (Focus::Prev(false), "focus prev"),
+        (Focus::Next(true), "focus next sibling"),
+        (Focus::Child, "focus child"),
+        (Focus::Parent, "focus parent"),
+        (Focus::Output(FocusOutput::Up), "focus output up"),
+        (Focus::Output(FocusOutput::Right), "focus output right"),
+        (Focus::Output(FocusOutput::Down), "focus output down"),
+        (Focus::Output(FocusOutput::Left), "focus output left"),
+        (
+            Focus::Output(FocusOutput::Name("HDMI-A-1".into())),
+            "focus output HDMI-A-1",
+        ),
+        (Focus::Tiling, "focus tiling"),
+        (Focus::Floating, "focus floating"),
+        (Focus::ModeToggle, "focus mode_toggle"),
+    ] {
+        check(SubCommand::Focus(focus), expected);
+    }
+}
+
+#[test]
+fn fullscreen() {
+    check(
+        SubCommand::Fullscreen(EnDisTog::Enable, FullscreenGlobal::No),
+        "fullscreen enable",
+    );
+    check(
+        SubCommand::Fullscreen(EnDisTog::Toggle, FullscreenGlobal::Global),
+        "fullscreen toggle global",
+    );
+}
+
+#[test]
+fn gaps() {
+    check(
+        SubCommand::Gaps(
+            GapsDirection::Inner,
+            GapsWorkspaces::All,
+            GapsModification::Plus,
+            5,
+        ),
+        "gaps inner all plus 5",
+    );
+    check(
+        SubCommand::set_gaps_current(GapsDirection::Top, 10),
+        "gaps top current set 10",
+    );
+    check(
+        SubCommand::Gaps(
+            GapsDirection::Outer,
+            GapsWorkspaces::Current,
+            GapsModification::Toggle,
+            3,
+        ),
+        "gaps outer current toggle 3",
+    );
+    check(
+        SubCommand::Gaps(
+            GapsDirection::Outer,
+            GapsWorkspaces::Current,
+            GapsModification::Minus,
+            3,
+        ),
+        "gaps outer current minus 3",
+    );
+    check(
+        SubCommand::Gaps(
+            GapsDirection::Horizontal,
+            GapsWorkspaces::Current,
+            GapsModification::Plus,
+            5,
+        ),
+        "gaps horizontal current plus 5",
+    );
+    check(
+        SubCommand::Gaps(
+            GapsDirection::Left,
+            GapsWorkspaces::All,
+            GapsModification::Minus,
+            2,
+        ),
+        "gaps left all minus 2",
+    );
+    check(
+        SubCommand::set_gaps_all(GapsDirection::Inner, 10),
+        "gaps inner all set 10",
+    );
+    check(
+        SubCommand::reset_gaps_current(GapsDirection::Outer),
+        "gaps outer current set 0",
+    );
+}
+
+#[test]
+fn layout() {
+    for (layout, expected) in [
+        (Layout::Default, "layout default"),
+        (Layout::Splith, "layout splith"),
+        (Layout::Splitv, "layout splitv"),
+        (Layout::Stacking, "layout stacking"),
+        (Layout::Tabbed, "layout tabbed"),
+        (Layout::Toggle(LayoutToggle::None), "layout toggle"),
+        (Layout::Toggle(LayoutToggle::Split), "layout toggle split"),
+        (Layout::Toggle(LayoutToggle::All), "layout toggle all"),
+        (
+            Layout::Toggle(LayoutToggle::Options(vec![
+                LayoutToggleOptions::Split,
+                LayoutToggleOptions::Tabbed,
+                LayoutToggleOptions::Stacking,
+                LayoutToggleOptions::Splitv,
+                LayoutToggleOptions::Splith,
+            ])),
+            "layout toggle split tabbed stacking splitv splith",
+        ),
+    ] {
+        check(SubCommand::Layout(layout), expected);
+    }
+}
+
+#[test]
+fn move_() {
+    let workspace = || Workspace::Name(WorkspaceName::Simple("web".into()));
+    for (command, expected) in [
+        (Move::Left(10), "move left 10 px"),
+        (Move::Right(10), "move right 10 px"),
+        (Move::Up(10), "move up 10 px"),
+        (Move::Down(10), "move down 10 px"),
+        (Move::PositionCenter, "move position center"),
+        (
+            Move::AbsolutePositionCenter,
+            "move absolute position center",
+        ),
+        (Move::PositionCursor, "move position cursor"),
+        (
+            Move::Workspace(workspace()),
+            "move container to workspace web",
+        ),
+        (
+            Move::WorkspaceNoAutoBackAndForth(workspace()),
+            "move --no-auto-back-and-forth container to workspace web",
+        ),
+        (Move::Scratchpad, "move container to scratchpad"),
+        (
+            Move::ContainerToOutput(Output::Left),
+            "move container to output left",
+        ),
+        (
+            Move::WorkspaceToOutput(Output::Name("DP-1".into())),
+            "move workspace to output DP-1",
+        ),
+    ] {
+        check(SubCommand::Move(command), expected);
+    }
+}
+
+#[test]
+fn move_position() {
+    check(
+        SubCommand::Move(Move::position_px(10, 20)),
+        "move position 10 px 20 px",
+    );
+    check(
+        SubCommand::Move(Move::position_ppt(10, 20)),
+        "move position 10 ppt 20 ppt",
+    );
+    check(
+        SubCommand::Move(Move::Position(Length::Ppt(0), Length::Default(1))),
+        "move position 0 ppt 1",
+    );
+    check(
+        SubCommand::Move(Move::absolute_position_px(10, 20)),
+        "move absolute position 10 px 20 px",
+    );
+}
+
+#[test]
+fn move_mark() {
+    check(
+        SubCommand::Move(Move::Mark("web".into())),
+        "move container to mark web",
+    );
+}
+
+#[test]
+fn rename_workspace() {
+    check(
+        SubCommand::RenameWorkspace("old".into(), "new".into()),
+        "rename workspace old to new",
+    );
+}
+
+#[test]
+fn resize() {
+    for (resize, expected) in [
+        (Resize::GrowWidth(Length::Px(10)), "resize grow width 10 px"),
+        (
+            Resize::ShrinkWidth(Length::Ppt(10)),
+            "resize shrink width 10 ppt",
+        ),
+        (
+            Resize::GrowHeight(Length::Default(10)),
+            "resize grow height 10",
+        ),
+        (
+            Resize::ShrinkHeight(Length::Px(10)),
+            "resize shrink height 10 px",
+        ),
+        (Resize::SetHeight(Length::Px(10)), "resize set height 10 px"),
+        (Resize::SetWidth(Length::Ppt(50)), "resize set width 50 ppt"),
+        (
+            Resize::Set(Length::Px(10), Length::Px(20)),
+            "resize set width 10 px height 20 px",
+        ),
+    ] {
+        check(SubCommand::Resize(resize), expected);
+    }
+}
+
+#[test]
+fn swap() {
+    check(SubCommand::swap_with_id("42"), "swap container with id 42");
+    check(
+        SubCommand::swap_with_con_id("42"),
+        "swap container with con_id 42",
+    );
+    check(
+        SubCommand::swap_with_mark("web"),
+        "swap container with mark web",
+    );
+}
+
+#[test]
+fn criterialess_command() {
+    use CriterialessCommand as C;
+
+    for (workspace, expected) in [
+        (
+            Workspace::Name(WorkspaceName::Simple("2".into())),
+            "assign [app_id=\"foot\"] → workspace 2",
+        ),
+        (
+            Workspace::Name(WorkspaceName::WithNumber(2, "web".into())),
+            "assign [app_id=\"foot\"] → workspace 2:web",
+        ),
+        (
+            Workspace::Number(WorkspaceName::Simple("3".into())),
+            "assign [app_id=\"foot\"] → workspace number 3",
+        ),
+        (Workspace::Next, "assign [app_id=\"foot\"] → workspace next"),
+        (
+            Workspace::BackAndForth,
+            "assign [app_id=\"foot\"] → workspace back_and_forth",
+        ),
+    ] {
+        check(
+            C::AssignWorkspace(CriteriaList::app_id("foot"), workspace),
+            expected,
+        );
+    }
+    for (output, expected) in [
+        (
+            Output::Name("DP-1".into()),
+            "assign [class=\"Firefox\"] → output DP-1",
+        ),
+        (Output::Left, "assign [class=\"Firefox\"] → output left"),
+        (
+            Output::Current,
+            "assign [class=\"Firefox\"] → output current",
+        ),
+    ] {
+        check(
+            C::AssignOutput(CriteriaList::class("Firefox"), output),
+            expected,
+        );
+    }
+    check(
+        C::Bindsym(Default::default(), SymKey::key("Return"), exec("foot")),
+        "bindsym Return exec foot",
+    );
+    check(
+        C::bindswitch_lid_close("reload"),
+        "bindswitch lid:on reload",
+    );
+    check(
+        C::ClientBackground("#FFFFFF".into()),
+        "client.background #FFFFFF",
+    );
+    check(C::DefaultBorder(DefaultBorder::None), "default_border none");
+    check(
+        C::DefaultBorder(DefaultBorder::Normal(Some(2))),
+        "default_border normal 2",
+    );
+    check(
+        C::DefaultFloatingBorder(DefaultBorder::Pixel(None)),
+        "default_floating_border pixel",
+    );
+    check(C::Exec("foot".into()), "exec foot");
+    check(C::ExecAlways("mako".into()), "exec_always mako");
+    check(
+        C::FloatingMaximumSize(-1, -1),
+        "floating_maximum_size -1 x -1",
+    );
+    check(
+        C::FloatingMinimumSize(100, 50),
+        "floating_minimum_size 100 x 50",
+    );
+    check(
+        C::FloatingModifier(Some("Mod4".into()), FloatingModifierMode::Normal),
+        "floating_modifier Mod4 normal",
+    );
+    check(
+        C::FloatingModifier(Some("Mod1".into()), FloatingModifierMode::Inverse),
+        "floating_modifier Mod1 inverse",
+    );
+    check(C::floating_modifier_none(), "floating_modifier none");
+    check(
+        C::ForceDisplayUrgencyHint(500),
+        "force_display_urgency_hint 500 ms",
+    );
+    check(C::TitlebarBorderThickness(1), "titlebar_border_thickness 1");
+    check(
+        C::TitlebarPadding(NonZeroU32::new(5).unwrap(), NonZeroU32::new(1)),
+        "titlebar_padding 5 1",
+    );
+    check(C::Gaps(GapsDirection::Inner, 5), "gaps inner 5");
+    check(
+        C::HideEdgeBordersI3(EdgeBorders::Both),
+        "hide_edge_borders --i3 both",
+    );
+    check(
+        C::Input("type:touchpad".into(), vec!["tap".into(), "enabled".into()]),
+        "input type:touchpad tap enabled",
+    );
+    check(
+        C::Seat("*".into(), vec!["hide_cursor".into(), "5000".into()]),
+        "seat * hide_cursor 5000",
+    );
+    check(C::Kill, "kill");
+    for (modification, expected) in [
+        (MarkModification::Add, "mark --add web"),
+        (MarkModification::AddToggle, "mark --add --toggle web"),
+        (MarkModification::Replace, "mark --replace web"),
+        (
+            MarkModification::ReplaceToggle,
+            "mark --replace --toggle web",
+        ),
+    ] {
+        check(C::Mark(modification, "web".into()), expected);
+    }
+    check(C::Mode("resize".into()), "mode resize");
+    check(
+        C::ModeCmds("resize".into(), vec!["bindsym".into(), "Escape".into()]),
+        "mode resize bindsym Escape",
+    );
+    check(
+        C::ModePangoMarkupCmds("<b>resize</b>".into(), vec![]),
+        "mode --pango_markup <b>resize</b>",
+    );
+    check(
+        C::Output("eDP-1".into(), vec![OutputSubcommand::Disable]),
+        "output eDP-1 disable",
+    );
+    check(C::Set("mod".into(), "Mod4".into()), "set $mod Mod4");
+    check(C::ShowMarks(YesNo::Yes), "show_marks yes");
+    check(C::Opacity(OpacityModification::Set, 0.5), "opacity set 0.5");
+    check(C::TilingDrag(EnDisTog::Disable), "tiling_drag disable");
+    check(C::TilingDragThreshold(9), "tiling_drag_threshold 9");
+    check(
+        C::Unbindswitch(Switch::Tablet, SwitchState::Toggle),
+        "unbindswitch tablet:toggle",
+    );
+    check(
+        C::Unbindsym(Default::default(), SymKey::key("q")),
+        "unbindsym q",
+    );
+    check(C::Unmark("web".into()), "unmark web");
+    check(
+        C::Workspace(Workspace::Number(WorkspaceName::Simple("1".into()))),
+        "workspace number 1",
+    );
+    check(
+        C::WorkspaceGaps(
+            WorkspaceName::WithNumber(1, "web".into()),
+            GapsDirection::Outer,
+            10,
+        ),
+        "workspace 1:web gaps outer 10",
+    );
+    check(
+        C::WorkspaceOutput(
+            WorkspaceName::Simple("1".into()),
+            vec1![Output::Name("DP-1".into()), Output::Name("eDP-1".into())],
+        ),
+        "workspace 1 output DP-1 eDP-1",
+    );
+    check(
+        C::WorkspaceAutoBackAndForth(YesNo::No),
+        "workspace_auto_back_and_forth no",
+    );
+}
+
+#[test]
+fn criterialess_command_enums() {
+    use CriterialessCommand as C;
+
+    for (focus, expected) in [
+        (MouseFocus::Yes, "focus_follows_mouse yes"),
+        (MouseFocus::No, "focus_follows_mouse no"),
+        (MouseFocus::Always, "focus_follows_mouse always"),
+    ] {
+        check(C::FocusFollowsMouse(focus), expected);
+    }
+    for (wrapping, expected) in [
+        (FocusWrapping::Yes, "focus_wrapping yes"),
+        (FocusWrapping::No, "focus_wrapping no"),
+        (FocusWrapping::Force, "focus_wrapping force"),
+        (FocusWrapping::Workspace, "focus_wrapping workspace"),
+    ] {
+        check(C::FocusWrapping(wrapping), expected);
+    }
+    for (borders, expected) in [
+        (EdgeBorders::None, "hide_edge_borders none"),
+        (EdgeBorders::Vertical, "hide_edge_borders vertical"),
+        (EdgeBorders::Horizontal, "hide_edge_borders horizontal"),
+        (EdgeBorders::Both, "hide_edge_borders both"),
+        (EdgeBorders::Smart, "hide_edge_borders smart"),
+        (EdgeBorders::SmartNoGaps, "hide_edge_borders smart_no_gaps"),
+    ] {
+        check(C::HideEdgeBorders(borders), expected);
+    }
+    for (borders, expected) in [
+        (SmartBorders::On, "smart_borders on"),
+        (SmartBorders::NoGaps, "smart_borders no_gaps"),
+        (SmartBorders::Off, "smart_borders off"),
+    ] {
+        check(C::SmartBorders(borders), expected);
+    }
+    for (gaps, expected) in [
+        (SmartGaps::On, "smart_gaps on"),
+        (SmartGaps::Off, "smart_gaps off"),
+        (SmartGaps::Toggle, "smart_gaps toggle"),
+        (SmartGaps::InverseOuter, "smart_gaps inverse_outer"),
+    ] {
+        check(C::SmartGaps(gaps), expected);
+    }
+    for (warping, expected) in [
+        (MouseWarping::Output, "mouse_warping output"),
+        (MouseWarping::Container, "mouse_warping container"),
+        (MouseWarping::None, "mouse_warping none"),
+    ] {
+        check(C::MouseWarping(warping), expected);
+    }
+    for (focus, expected) in [
+        (
+            WindowActivationFocus::Smart,
+            "focus_on_window_activation smart",
+        ),
+        (
+            WindowActivationFocus::Urgent,
+            "focus_on_window_activation urgent",
+        ),
+        (
+            WindowActivationFocus::Focus,
+            "focus_on_window_activation focus",
+        ),
+        (
+            WindowActivationFocus::None,
+            "focus_on_window_activation none",
+        ),
+    ] {
+        check(C::FocusOnWindowActivation(focus), expected);
+    }
+    for (popup, expected) in [
+        (
+            PopupDuringFullscreen::Smart,
+            "popup_during_fullscreen smart",
+        ),
+        (
+            PopupDuringFullscreen::Ignore,
+            "popup_during_fullscreen ignore",
+        ),
+        (
+            PopupDuringFullscreen::LeaveFullscreen,
+            "popup_during_fullscreen leave_fullscreen",
+        ),
+    ] {
+        check(C::PopupDuringFullscreen(popup), expected);
+    }
+    for (align, expected) in [
+        (TitleAlign::Left, "title_align left"),
+        (TitleAlign::Center, "title_align center"),
+        (TitleAlign::Right, "title_align right"),
+    ] {
+        check(C::TitleAlign(align), expected);
+    }
+    for (modification, expected) in [
+        (OpacityModification::Plus, "opacity plus 0.1"),
+        (OpacityModification::Minus, "opacity minus 0.1"),
+    ] {
+        check(C::Opacity(modification, 0.1), expected);
+    }
+}
+
+#[test]
+fn client_class() {
+    let color = Color::rgb(0x0A, 0x0B, 0x0C);
+    for (class, name) in [
+        (Class::Focused, "focused"),
+        (Class::FocusedInactive, "focused_inactive"),
+        (Class::FocusedTabTitle, "focused_tab_title"),
+        (Class::Placeholder, "placeholder"),
+        (Class::Unfocused, "unfocused"),
+        (Class::Urgent, "urgent"),
+    ] {
+        check(
+            CriterialessCommand::client_class(class, color, color, color).into_command(),
+            &format!("client.{name} #0A0B0C #0A0B0C #0A0B0C"),
+        );
+    }
+    check(Color::rgba(0, 0, 0, 0x0F), "#0000000F");
+}
+
+#[test]
+fn urgent() {
+    for (urgent, expected) in [
+        (Urgent::Enable, "urgent enable"),
+        (Urgent::Disable, "urgent disable"),
+        (Urgent::Allow, "urgent allow"),
+        (Urgent::Deny, "urgent deny"),
+    ] {
+        check(CriterialessCommand::Urgent(urgent), expected);
+    }
+}
+
+#[test]
+fn criteria_commands() {
+    check(
+        CriterialessCommand::ForWindow(
+            CriteriaList::app_id("foot"),
+            SubCommand::Floating(EnDisTog::Enable).into(),
+        ),
+        "for_window [app_id=\"foot\"] floating enable",
+    );
+    check(
+        CriterialessCommand::NoFocus(CriteriaList::title(".")),
+        "no_focus [title=\".\"]",
+    );
+}
+
+#[test]
+fn config_command() {
+    check(ConfigCommand::Bar(None, vec![]), "bar");
+    check(
+        ConfigCommand::Bar(Some("main".into()), vec!["mode".into(), "dock".into()]),
+        "bar main mode dock",
+    );
+    for (orientation, expected) in [
+        (
+            DefaultOrientation::Horizontal,
+            "default_orientation horizontal",
+        ),
+        (DefaultOrientation::Vertical, "default_orientation vertical"),
+        (DefaultOrientation::Auto, "default_orientation auto"),
+    ] {
+        check(ConfigCommand::DefaultOrientation(orientation), expected);
+    }
+    check(
+        ConfigCommand::include_glob("/etc/sway/config.d/*").unwrap(),
+        "include /etc/sway/config.d/*",
+    );
+    check(ConfigCommand::SwaybgCommand("-".into()), "swaybg_command -");
+    check(
+        ConfigCommand::SwaynagCommand("swaynag".into()),
+        "swaynag_command swaynag",
+    );
+    for (layout, expected) in [
+        (WorkspaceLayout::Default, "workspace_layout default"),
+        (WorkspaceLayout::Stacking, "workspace_layout stacking"),
+        (WorkspaceLayout::Tabbed, "workspace_layout tabbed"),
+    ] {
+        check(ConfigCommand::WorkspaceLayout(layout), expected);
+    }
+    for (xwayland, expected) in [
+        (Xwayland::Enable, "xwayland enable"),
+        (Xwayland::Disable, "xwayland disable"),
+        (Xwayland::Force, "xwayland force"),
+    ] {
+        check(ConfigCommand::Xwayland(xwayland), expected);
+    }
+    check(BarPosition::Top, "top");
+    check(BarPosition::Bottom, "bottom");
+    check(BarMode::Dock, "dock");
+    check(BarMode::Hide, "hide");
+    check(BarMode::Invisible, "invisible");
+    check(BarMode::Overlay, "overlay");
+    check(BarHiddenState::Hide, "hide");
+    check(BarHiddenState::Show, "show");
+}
+
+#[test]
+fn criteria() {
+    let value = |value: &str| OrFocused::Value(value.to_owned());
+    for (criteria, expected) in [
+        (Criteria::AppId(value("foot")), "app_id=\"foot\""),
+        (Criteria::Class(OrFocused::Focused), "class=\"__focused__\""),
+        (Criteria::ConId(OrFocused::Value(3)), "con_id=\"3\""),
+        (Criteria::ConMark("web".into()), "con_mark=\"web\""),
+        (Criteria::Floating, "floating"),
+        (Criteria::Id(7), "id=\"7\""),
+        (Criteria::Instance(value("main")), "instance=\"main\""),
+        (Criteria::Pid(42), "pid=\"42\""),
+        (Criteria::Shell(value("xwayland")), "shell=\"xwayland\""),
+        (Criteria::Tiling, "tiling"),
+        (Criteria::Title(value(".*")), "title=\".*\""),
+        (
+            Criteria::Urgent(criteria::Urgent::Latest),
+            "urgent=\"latest\"",
+        ),
+        (
+            Criteria::WindowRole(value("popup")),
+            "window_role=\"popup\"",
+        ),
+        (
+            Criteria::WindowType(WindowType::DropdownMenu),
+            "window_type=\"dropdown_menu\"",
+        ),
+        (Criteria::Workspace(value("web")), "workspace=\"web\""),
+    ] {
+        check(criteria, expected);
+    }
+    check(
+        CriteriaList::floating() | Criteria::AppId(value("foot")),
+        "[floating app_id=\"foot\"]",
+    );
+}
@@ -0,0 +1,362 @@
+//! Property tests for the string representation
+//!
+//! Types implementing `FromStr` need to parse their own output. Commands also
+//! have to start with the command keyword and contain the generated argument
+//! values.
+
+use std::time::Duration;
+
+use proptest::{collection::vec, prelude::*};
+use sway_command::{
+    commands::*,
+    criteria::{Criteria, CriteriaList, OrFocused, Urgent, WindowType},
+};
+use vec1::Vec1;
+
+/// Arbitrary single word argument, without `_` to not be confused with a
+/// placeholder
+fn word() -> impl Strategy<Value = String> {
+    "[a-zA-Z0-9.:=-]{1,6}"
+}
+
+/// Arbitrary argument of up to three [`word`]s
+///
+/// Words are separated by single spaces, as the parsers trim and split
+/// arguments at whitespace.
+fn value() -> impl Strategy<Value = String> {
+    vec(word(), 1..4).prop_map(|words| words.join(" "))
+}
+
+fn or_focused() -> impl Strategy<Value = OrFocused<String>> {
+    prop_oneof![Just(OrFocused::Focused), value().prop_map(OrFocused::Value)]
+}
+
+fn criteria() -> impl Strategy<Value = Criteria> {
+    prop_oneof![
+        or_focused().prop_map(Criteria::AppId),
+        or_focused().prop_map(Criteria::Class),
+        prop_oneof![
+            Just(OrFocused::Focused),
+            any::<u32>().prop_map(OrFocused::Value)
+        ]
+        .prop_map(Criteria::ConId),
+        value().prop_map(Criteria::ConMark),
+        Just(Criteria::Floating),
+        any::<u32>().prop_map(Criteria::Id),
+        or_focused().prop_map(Criteria::Instance),
+        any::<u32>().prop_map(Criteria::Pid),
+        or_focused().prop_map(Criteria::Shell),
+        Just(Criteria::Tiling),
+        or_focused().prop_map(Criteria::Title),
+        prop_oneof![
+            Just(Urgent::First),
+            Just(Urgent::Last),
+            Just(Urgent::Latest),
+            Just(Urgent::Newest),
+            Just(Urgent::Oldest),
+            Just(Urgent::Recent),
+        ]
+        .prop_map(Criteria::Urgent),
+        or_focused().prop_map(Criteria::WindowRole),
+        prop_oneof![
+            Just(WindowType::Normal),
+            Just(WindowType::Dialog),
+            Just(WindowType::Utility),
+            Just(WindowType::Toolbar),
+            Just(WindowType::Splash),
+            Just(WindowType::Menu),
+            Just(WindowType::DropdownMenu),
+            Just(WindowType::PopupMenu),
+            Just(WindowType::Tooltip),
+            Just(WindowType::Notification),
+        ]
+        .prop_map(Criteria::WindowType),
+        or_focused().prop_map(Criteria::Workspace),
+    ]
+}
+
+fn workspace_name() -> impl Strategy<Value = String> {
+    prop_oneof![
+        "ws-[a-z0-9]{0,8}",
+        (any::<u32>(), "[a-z][a-z0-9]{0,8}").prop_map(|(number, name)| format!("{number}:{name}")),
+    ]
+}
+
+/// Workspace selectors as displayed, as [`Workspace`] does not implement
+/// `Debug`
+fn workspace() -> impl Strategy<Value = String> {
+    prop_oneof![
+        workspace_name(),
+        workspace_name().prop_map(|name| format!("number {name}")),
+        Just("prev".to_owned()),
+        Just("next".to_owned()),
+        Just("current".to_owned()),
+        Just("prev_on_output".to_owned()),
+        Just("next_on_output".to_owned()),
+        Just("back_and_forth".to_owned()),
+    ]
+}
+
+fn parse_workspace(workspace: &str) -> Workspace {
+    workspace.parse().unwrap()
+}
+
+fn gaps_direction() -> impl Strategy<Value = &'static str> {
+    prop::sample::select(
+        &[
+            "inner",
+            "outer",
+            "horizontal",
+            "vertical",
+            "top",
+            "right",
+            "bottom",
+            "left",
+        ][..],
+    )
+}
+
+fn parse_gaps_direction(direction: &str) -> GapsDirection {
+    direction.parse().unwrap()
+}
+
+fn en_dis_tog() -> impl Strategy<Value = usize> {
+    0..3usize
+}
+
+fn to_en_dis_tog(idx: usize) -> EnDisTog {
+    [EnDisTog::Enable, EnDisTog::Disable, EnDisTog::Toggle][idx]
+}
+
+fn en_dis_tog_keyword(idx: usize) -> &'static str {
+    ["enable", "disable", "toggle"][idx]
+}
+
+/// A displayed command with the keyword it has to start with and the argument
+/// values it has to contain, as the commands do not implement `Debug`
+#[derive(Debug, Clone)]
+struct Displayed {
+    command: String,
+    keyword: &'static str,
+    values: Vec<String>,
+}
+
+fn displayed(
+    command: impl ToString,
+    keyword: &'static str,
+    values: impl IntoIterator<Item = impl ToString>,
+) -> Displayed {
+    Displayed {
+        command: command.to_string(),
+        keyword,
+        values: values.into_iter().map(|v| v.to_string()).collect(),
+    }
+}
+
+fn sub_command() -> impl Strategy<Value = Displayed> {
+    prop_oneof![
+        prop::sample::select(vec![
+            (SubCommand::Exit.to_string(), "exit"),
+            (SubCommand::Reload.to_string(), "reload"),
+            (SubCommand::ScratchpadShow.to_string(), "scratchpad"),
+        ])
+        .prop_map(|(command, keyword)| displayed(command, keyword, ["" as &str; 0])),
+        en_dis_tog().prop_map(|state| displayed(
+            SubCommand::Floating(to_en_dis_tog(state)),
+            "floating",
+            [en_dis_tog_keyword(state)]
+        )),
+        en_dis_tog().prop_map(|state| displayed(
+            SubCommand::Sticky(to_en_dis_tog(state)),
+            "sticky",
+            [en_dis_tog_keyword(state)]
+        )),
+        (any::<bool>(), any::<bool>()).prop_map(|(next, sibling)| {
+            let focus = if next {
+                Focus::Next(sibling)
+            } else {
+                Focus::Prev(sibling)
+            };
+            let mut values = vec![if next { "next" } else { "prev" }];
+            if sibling {
+                values.push("sibling");
+            }
+            displayed(SubCommand::Focus(focus), "focus", values)
+        }),
+        (gaps_direction(), any::<bool>(), any::<u32>()).prop_map(|(direction, all, amount)| {
+            let parsed = parse_gaps_direction(direction);
+            let (command, workspaces) = if all {
+                (SubCommand::set_gaps_all(parsed, amount), "all")
+            } else {
+                (SubCommand::set_gaps_current(parsed, amount), "current")
+            };
+            displayed(
+                command,
+                "gaps",
+                [direction.to_owned(), format!("{workspaces} set {amount}")],
+            )
+        }),
+        workspace().prop_map(|workspace| displayed(
+            SubCommand::Move(Move::Workspace(parse_workspace(&workspace))),
+            "move",
+            [format!("workspace {workspace}")]
+        )),
+        (any::<u32>(), any::<u32>()).prop_map(|(x, y)| displayed(
+            SubCommand::Move(Move::position_px(x, y)),
+            "move",
+            [format!("position {x} px {y} px")]
+        )),
+        proptest::option::of(value()).prop_map(|comment| displayed(
+            SubCommand::Nop(comment.clone()),
+            "nop",
+            comment
+        )),
+        value().prop_map(|name| displayed(
+            SubCommand::RenameFocusedWorkspace(name.clone()),
+            "rename",
+            [format!("to {name}")]
+        )),
+        word().prop_map(|mark| displayed(
+            SubCommand::swap_with_mark(mark.clone()),
+            "swap",
+            [format!("mark {mark}")]
+        )),
+    ]
+}
+
+fn criterialess_command() -> impl Strategy<Value = Displayed> {
+    prop_oneof![
+        Just(displayed(
+            CriterialessCommand::Kill,
+            "kill",
+            ["" as &str; 0]
+        )),
+        value().prop_map(|command| displayed(
+            CriterialessCommand::Exec(command.clone()),
+            "exec",
+            [command]
+        )),
+        workspace().prop_map(|workspace| displayed(
+            CriterialessCommand::Workspace(parse_workspace(&workspace)),
+            "workspace",
+            [workspace]
+        )),
+        (gaps_direction(), any::<u32>()).prop_map(|(direction, amount)| displayed(
+            CriterialessCommand::Gaps(parse_gaps_direction(direction), amount),
+            "gaps",
+            [format!("{direction} {amount}")]
+        )),
+        (workspace_name(), gaps_direction(), any::<u32>()).prop_map(|(name, direction, amount)| {
+            displayed(
+                CriterialessCommand::workspace_gaps(
+                    name.clone(),
+                    parse_gaps_direction(direction),
+                    amount,
+                ),
+                "workspace",
+                [name, format!("gaps {direction} {amount}")],
+            )
+        }),
+        (vec(criteria(), 1..4), workspace()).prop_map(|(criteria, workspace)| {
+            let criteria: CriteriaList = Vec1::try_from_vec(criteria).unwrap().into();
+            displayed(
+                CriterialessCommand::assign_to_workspace(
+                    criteria.clone(),
+                    parse_workspace(&workspace),
+                ),
+                "assign",
+                [criteria.to_string(), format!("workspace {workspace}")],
+            )
+        }),
+        ("[a-zA-Z][a-zA-Z0-9]{0,8}", value()).prop_map(|(name, value)| displayed(
+            CriterialessCommand::try_set(&name, &value).unwrap(),
+            "set",
+            [format!("${name}"), value]
+        )),
+        (any::<u32>(), any::<bool>()).prop_map(|(ms, duration)| displayed(
+            if duration {
+                CriterialessCommand::force_display_urgency_hint_duration(Duration::from_millis(
+                    ms.into(),
+                ))
+            } else {
+                CriterialessCommand::force_display_urgency_hint(ms)
+            },
+            "force_display_urgency_hint",
+            [format!("{ms} ms")]
+        )),
+    ]
+}
+
+/// The output starts with the expected keyword, contains every argument value
+/// as whole words and no unformatted field placeholders
+fn assert_command_shape(displayed: Displayed) -> Result<(), TestCaseError> {
+    let Displayed {
+        command,
+        keyword,
+        values,
+    } = displayed;
+    prop_assert_eq!(
+        command.split(' ').next(),
+        Some(keyword),
+        "wrong keyword in `{}`",
+        command
+    );
+    for value in values {
+        prop_assert!(
+            format!(" {command} ").contains(&format!(" {value} ")),
+            "`{value}` missing in `{command}`"
+        );
+    }
+    prop_assert!(
+        !command.contains("_0") && !command.contains("_1"),
+        "unformatted placeholder in `{command}`"
+    );
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn criteria_roundtrip(criteria in criteria()) {
+        prop_assert_eq!(criteria.to_string().parse::<Criteria>()?, criteria);
+    }
+
+    #[test]
+    fn criteria_list_roundtrip(criteria in vec(criteria(), 1..6)) {
+        let list: CriteriaList = Vec1::try_from_vec(criteria).unwrap().into();
+        prop_assert!(list.to_string().parse::<CriteriaList>()? == list);
+    }
+
+    #[test]
+    fn workspace_roundtrip(workspace in workspace()) {
+        prop_assert_eq!(parse_workspace(&workspace).to_string(), workspace);
+    }
+
+    #[test]
+    fn gaps_direction_roundtrip(direction in gaps_direction()) {
+        let parsed: GapsDirection = direction.to_uppercase().parse()?;
+        prop_assert_eq!(parsed.to_string(), direction);
+    }
+
+    #[test]
+    fn sub_command_shape(command in sub_command()) {
+        assert_command_shape(command)?;
+    }
+
+    #[test]
+    fn sub_command_roundtrip(command in sub_command()) {
+        let parsed: SubCommand = command.command.parse()?;
+        prop_assert!(!matches!(parsed, SubCommand::Raw(_)), "`{}` parsed as raw", command.command);
+        prop_assert_eq!(parsed.to_string(), command.command);
+    }
+
+    #[test]
+    fn criterialess_command_shape(command in criterialess_command()) {
+        assert_command_shape(command)?;
+    }
+
+    #[test]
+    fn criterialess_command_roundtrip(command in criterialess_command()) {
+        let parsed: CriterialessCommand = command.command.parse()?;
+        prop_assert_eq!(parsed.to_string(), command.command);
+    }
+}